@@ -1,8 +1,21 @@
 ///! Logic related to calculating frames and specifying raid parameters.
+pub mod advance;
+pub mod alignment;
+pub mod blink;
+pub mod crystal;
+pub mod den;
+pub mod dump;
+pub mod event;
 pub mod filter;
 pub mod frame;
+pub mod maxlair;
 pub mod mon;
 pub mod overworld;
 pub mod personal;
+pub mod planner;
 pub mod raid;
 mod rng;
+pub mod slot;
+pub mod timer;
+pub mod tracker;
+pub mod trainer;
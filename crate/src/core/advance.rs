@@ -0,0 +1,164 @@
+//! How the overworld RNG moves with NPCs on the map, and when the player closes the menu.
+//!
+//! Each tick, the player and each NPC on the map advance the RNG once. Closing the menu rerolls
+//! the blink timer of the player and each NPC with a `rand(91)` each, which can take more than
+//! one advance when the roll is rejected, so the advances taken depend on the state.
+use super::rng::Rng;
+use wasm_bindgen::prelude::*;
+
+/// Range of each blink timer reroll on menu close.
+const BLINK_TIMER_MAX: u32 = 91;
+
+/// Most menu closes a plan can ask for. Targets further away return no plan.
+const MAX_MENU_CLOSES: u32 = 10_000;
+
+/// Advances taken by the map around the player.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct AdvanceModel {
+    /// Number of NPCs on the map, not counting the player.
+    npc_count: u32,
+}
+
+/// Menu closes and raw advances needed to reach a target advance.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct MenuPlan {
+    menu_closes: u32,
+    /// Advances left to wait for after the last menu close, a whole number of ticks.
+    raw_advances: u64,
+}
+
+#[wasm_bindgen]
+impl MenuPlan {
+    #[wasm_bindgen(getter)]
+    pub fn menu_closes(&self) -> u32 {
+        self.menu_closes
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn raw_advances(&self) -> f64 {
+        self.raw_advances as f64
+    }
+}
+
+impl MenuPlan {
+    pub fn get_menu_closes(&self) -> u32 {
+        self.menu_closes
+    }
+
+    pub fn get_raw_advances(&self) -> u64 {
+        self.raw_advances
+    }
+}
+
+#[wasm_bindgen]
+impl AdvanceModel {
+    #[wasm_bindgen(constructor)]
+    pub fn new(npc_count: u32) -> Self {
+        AdvanceModel { npc_count }
+    }
+}
+
+impl AdvanceModel {
+    /// Returns the advances taken by each tick: one for the player and one for each NPC.
+    pub fn get_advances_per_tick(&self) -> u64 {
+        self.npc_count as u64 + 1
+    }
+
+    /// Closes the menu, returning the number of advances it took.
+    pub fn close_menu(&self, rng: &mut Rng) -> u64 {
+        // Count each call `next_int_max` would make, including rejected rolls.
+        let mask = BLINK_TIMER_MAX.next_power_of_two() - 1;
+        let mut advances = 0;
+        for _ in 0..=self.npc_count {
+            advances += 1;
+            while rng.next_int(mask) >= BLINK_TIMER_MAX {
+                advances += 1;
+            }
+        }
+        advances
+    }
+
+    /// Plans the menu closes to make from `state`, then the raw advances to wait for, to land on
+    /// `target` advances later. Closes the menu as many times as possible without overshooting,
+    /// while leaving a whole number of ticks to wait for. Returns nothing if no number of closes
+    /// works, or if the target is more than `MAX_MENU_CLOSES` closes away.
+    // `is_multiple_of` needs a newer toolchain than the crate supports.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn plan(&self, state: (u64, u64), target: u64) -> Option<MenuPlan> {
+        let per_tick = self.get_advances_per_tick();
+        let mut rng = Rng::from_state(state.0, state.1);
+        let mut plan = None;
+        let mut advance = 0;
+        for menu_closes in 0..=MAX_MENU_CLOSES {
+            if (target - advance) % per_tick == 0 {
+                plan = Some(MenuPlan {
+                    menu_closes,
+                    raw_advances: target - advance,
+                });
+            }
+            let taken = self.close_menu(&mut rng);
+            if advance + taken > target {
+                return plan;
+            }
+            advance += taken;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const STATE: (u64, u64) = (0x5e5c928d61792fed, 0xed608999e1410aa9);
+
+    #[test]
+    fn test_close_menu() {
+        // Taking at least one advance per blink timer, and matching `next_int_max` call for call.
+        let model = AdvanceModel::new(3);
+        let mut rng = Rng::from_state(STATE.0, STATE.1);
+        let mut expected = Rng::from_state(STATE.0, STATE.1);
+        for _ in 0..100 {
+            let taken = model.close_menu(&mut rng);
+            assert!(taken >= 4);
+            for _ in 0..4 {
+                expected.next_int_max(BLINK_TIMER_MAX);
+            }
+            assert_eq!(rng.get_state(), expected.get_state());
+        }
+    }
+
+    #[test]
+    fn test_plan() {
+        let model = AdvanceModel::new(2);
+        let target = 1000;
+        let plan = model.plan(STATE, target).unwrap();
+
+        // Following the plan lands exactly on the target, waiting whole ticks.
+        let mut rng = Rng::from_state(STATE.0, STATE.1);
+        let mut advance = 0;
+        for _ in 0..plan.get_menu_closes() {
+            advance += model.close_menu(&mut rng);
+        }
+        assert_eq!(advance + plan.get_raw_advances(), target);
+        assert_eq!(plan.get_raw_advances() % model.get_advances_per_tick(), 0);
+
+        // Any later number of closes either overshoots or splits a tick.
+        loop {
+            advance += model.close_menu(&mut rng);
+            if advance > target {
+                break;
+            }
+            assert_ne!((target - advance) % model.get_advances_per_tick(), 0);
+        }
+    }
+
+    #[test]
+    fn test_plan_limit() {
+        let model = AdvanceModel::new(0);
+        assert!(model.plan(STATE, 0).is_some());
+        assert_eq!(model.plan(STATE, u64::MAX), None);
+    }
+}
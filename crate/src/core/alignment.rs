@@ -0,0 +1,255 @@
+//! Checking which frame a den is on from what was seen after 3-day skips.
+use super::super::den_data::DEN_DATA;
+use super::den::{DenEncounter, GameVersion};
+use super::filter::FrameFilter;
+use super::frame::{FrameGenerator, FrameResult};
+use super::mon::Shininess;
+use super::raid::Raid;
+use super::rng::Rng;
+use wasm_bindgen::prelude::*;
+
+/// Something seen in a den, some number of frames after the frame being resolved.
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Observation {
+    offset: u32,
+    /// The den's encounters at the species and star level seen, as raids.
+    /// Entries can share a species and star level, so the mon seen may be any of them.
+    raids: Vec<Raid>,
+    is_shiny: bool,
+    /// Further checks for observations made after catching the mon.
+    filter: Option<FrameFilter>,
+}
+
+/// Finds the frames consistent with a sequence of observations.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FrameResolver {
+    seed: u64,
+    encounters: Vec<DenEncounter>,
+    observations: Vec<Observation>,
+}
+
+#[wasm_bindgen]
+impl FrameResolver {
+    /// Creates a resolver for a den's frame 0 seed. Returns None if the den does not exist.
+    pub fn new(seed: u64, den: usize, version: GameVersion) -> Option<FrameResolver> {
+        let encounters = DEN_DATA.get_den(den)?.get_encounters(version).to_vec();
+        Some(FrameResolver {
+            seed,
+            encounters,
+            observations: Vec::new(),
+        })
+    }
+
+    /// Adds an observation `offset` frames after the frame being resolved, with the species,
+    /// form, G-max and star level seen as a raid. After a 3-day skip, the offset is 3.
+    /// Returns false, without adding it, if the den has no such encounter.
+    pub fn add_observation(&mut self, offset: u32, seen: Raid, is_shiny: bool) -> bool {
+        self.add(offset, seen, is_shiny, None)
+    }
+
+    /// Adds an observation that must also pass a filter, e.g. with the IVs or nature
+    /// of a caught mon. Returns false, without adding it, if the den has no such encounter.
+    pub fn add_filtered_observation(
+        &mut self,
+        offset: u32,
+        seen: Raid,
+        is_shiny: bool,
+        filter: FrameFilter,
+    ) -> bool {
+        self.add(offset, seen, is_shiny, Some(filter))
+    }
+
+    /// Returns true if every observation matches when the den is on the given frame.
+    pub fn matches(&self, frame: u32) -> bool {
+        self.matches_at(frame, Rng::get_seed_at_offset(self.seed, frame as usize))
+    }
+
+    /// Returns every frame within `window` frames of the expected frame that matches
+    /// the observations, in order.
+    pub fn resolve(&self, expected: u32, window: u32) -> Vec<u32> {
+        let start = expected.saturating_sub(window);
+        let end = expected.saturating_add(window);
+        let mut seed = Rng::get_seed_at_offset(self.seed, start as usize);
+        let mut frames = Vec::new();
+        for frame in start..=end {
+            if self.matches_at(frame, seed) {
+                frames.push(frame);
+            }
+            seed = Rng::get_seed_at_offset(seed, 1);
+        }
+        frames
+    }
+}
+
+impl FrameResolver {
+    /// Checks every observation against a frame, given the den seed at that frame.
+    fn matches_at(&self, frame: u32, seed: u64) -> bool {
+        self.observations.iter().all(|o| {
+            // Frames past the last one can't be reached, so they never match.
+            if frame.checked_add(o.offset).is_none() {
+                return false;
+            }
+            let seed = Rng::get_seed_at_offset(seed, o.offset as usize);
+            o.raids.iter().any(|&raid| {
+                let mut generator = FrameGenerator::new(raid, seed);
+                if let Some(filter) = o.filter {
+                    generator.set_filter(filter);
+                }
+                match generator.next() {
+                    Some(FrameResult::Pass(f)) => (f.shiny != Shininess::None) == o.is_shiny,
+                    _ => false,
+                }
+            })
+        })
+    }
+
+    fn add(
+        &mut self,
+        offset: u32,
+        seen: Raid,
+        is_shiny: bool,
+        filter: Option<FrameFilter>,
+    ) -> bool {
+        let stars = seen.get_stars();
+        let raids = self
+            .encounters
+            .iter()
+            .filter(|e| {
+                e.get_species() == seen.get_species()
+                    && e.get_alt_form() == seen.get_alt_form()
+                    && e.is_gmax() == seen.is_gmax()
+                    && e.has_stars(stars)
+            })
+            .map(|e| e.create_raid(stars))
+            .collect::<Vec<_>>();
+        if raids.is_empty() {
+            return false;
+        }
+
+        self.observations.push(Observation {
+            offset,
+            raids,
+            is_shiny,
+            filter,
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::filter::NatureFilter;
+    use super::super::mon::Nature;
+    use super::*;
+
+    // Den 99: 4* Wigglytuff. Frame 0 is a star shiny.
+    const SEED: u64 = 0x775b846f76f1b25d;
+
+    fn wigglytuff() -> Raid {
+        Raid::new(40, 0, 4, false, 4, 0, 0, 4)
+    }
+
+    fn new_resolver() -> FrameResolver {
+        FrameResolver::new(SEED, 99, GameVersion::Sword).unwrap()
+    }
+
+    fn shiny_frames(count: u32) -> Vec<u32> {
+        (0..count)
+            .filter(|&frame| {
+                let seed = Rng::get_seed_at_offset(SEED, frame as usize);
+                match FrameGenerator::new(wigglytuff(), seed).next() {
+                    Some(FrameResult::Pass(f)) => f.shiny != Shininess::None,
+                    _ => false,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_shiny() {
+        // Seeing a shiny three frames ahead pins down the frame.
+        let mut resolver = new_resolver();
+        assert!(resolver.add_observation(3, wigglytuff(), true));
+        assert_eq!(resolver.resolve(0, 0), Vec::<u32>::new());
+
+        let expected = shiny_frames(10_000)
+            .into_iter()
+            .filter(|&frame| frame >= 3)
+            .map(|frame| frame - 3)
+            .filter(|&frame| frame <= 20)
+            .collect::<Vec<u32>>();
+        assert_eq!(resolver.resolve(10, 10), expected);
+    }
+
+    #[test]
+    fn test_resolve_missed_skip() {
+        // The user expects to be on frame 5 but actually missed a skip.
+        let mut resolver = new_resolver();
+        assert!(resolver.add_observation(0, wigglytuff(), true));
+        assert!(resolver.add_observation(1, wigglytuff(), false));
+        assert!(resolver.add_observation(2, wigglytuff(), false));
+        assert!(resolver.matches(0));
+        assert!(!resolver.matches(1));
+        assert!(resolver.resolve(5, 5).contains(&0));
+        assert!(!resolver.resolve(5, 5).contains(&5));
+    }
+
+    #[test]
+    fn test_resolve_filtered() {
+        let seed = Rng::get_seed_at_offset(SEED, 4);
+        let nature = match FrameGenerator::new(wigglytuff(), seed).next() {
+            Some(FrameResult::Pass(f)) => f.nature,
+            _ => unreachable!(),
+        };
+        let other = if nature == Nature::Hardy {
+            Nature::Lonely
+        } else {
+            Nature::Hardy
+        };
+
+        let mut resolver = new_resolver();
+        assert!(resolver.add_filtered_observation(
+            0,
+            wigglytuff(),
+            false,
+            FrameFilter::new().set_nature(NatureFilter::from_natures(vec![nature])),
+        ));
+        assert!(resolver.matches(4));
+
+        let mut resolver = new_resolver();
+        resolver.add_filtered_observation(
+            0,
+            wigglytuff(),
+            false,
+            FrameFilter::new().set_nature(NatureFilter::from_natures(vec![other])),
+        );
+        assert!(!resolver.matches(4));
+    }
+
+    #[test]
+    fn test_reject_mismatch() {
+        assert_eq!(FrameResolver::new(SEED, 0, GameVersion::Sword), None);
+        assert_eq!(FrameResolver::new(SEED, 198, GameVersion::Sword), None);
+
+        // Den 99 has no 1* Wigglytuff, no G-max Wigglytuff, and no Pikachu at all.
+        let mut resolver = new_resolver();
+        assert!(!resolver.add_observation(3, Raid::new(40, 0, 4, false, 4, 0, 0, 1), true));
+        assert!(!resolver.add_observation(3, Raid::new(40, 0, 4, true, 4, 0, 0, 4), true));
+        assert!(!resolver.add_observation(3, Raid::new(25, 0, 4, false, 4, 0, 0, 4), true));
+        assert_eq!(resolver, new_resolver());
+
+        // The den's own encounter is used, whatever else the observation says.
+        assert!(resolver.add_observation(0, Raid::new(40, 0, 1, false, 2, 0, 1, 4), true));
+        assert!(resolver.matches(0));
+    }
+
+    #[test]
+    fn test_resolve_overflow() {
+        let mut resolver = new_resolver();
+        resolver.add_observation(3, wigglytuff(), false);
+        assert!(!resolver.matches(u32::MAX));
+        assert!(!resolver.matches(u32::MAX - 2));
+        assert!(resolver.resolve(u32::MAX, 2).is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! Recovering the overworld RNG state from the player's blinks.
+//!
+//! Each blink reveals the lowest bit of the RNG output on the advance it happens. That bit is the
+//! XOR of the lowest bits of both halves of the state, and every advance of xoroshiro128+ is
+//! linear over GF(2), so each blink is a linear equation in the 128 bits of the starting state.
+//! With enough independent blinks, the system has a single solution.
+use super::rng::Rng;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Number of bits in the full RNG state.
+const STATE_BITS: usize = 128;
+
+/// Reasons the state could not be recovered.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum BlinkError {
+    /// Not enough independent blinks; this many bits of the state are still unknown.
+    Ambiguous(u32),
+    /// No state produces every blink seen, so at least one was recorded wrong.
+    Inconsistent,
+}
+
+impl fmt::Display for BlinkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlinkError::Ambiguous(n) => write!(
+                f,
+                "Not enough blinks: {} bits of the state are still unknown",
+                n
+            ),
+            BlinkError::Inconsistent => write!(f, "No state matches every blink recorded"),
+        }
+    }
+}
+
+/// Solves the RNG state from a sequence of blinks.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug)]
+pub struct BlinkSolver {
+    /// The state reached from each single-bit starting state, advanced alongside the real one.
+    /// Since advancing is linear, these say which starting bits feed into each current bit.
+    basis: Vec<Rng>,
+    /// One equation per blink: the starting bits XORed together, and the bit seen.
+    equations: Vec<(u128, bool)>,
+    /// Number of advances since the starting state.
+    advances: u64,
+}
+
+#[wasm_bindgen]
+impl BlinkSolver {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        let basis = (0..STATE_BITS)
+            .map(|bit| {
+                let bit = 1u128 << bit;
+                Rng::from_state(bit as u64, (bit >> 64) as u64)
+            })
+            .collect();
+
+        BlinkSolver {
+            basis,
+            equations: Vec::new(),
+            advances: 0,
+        }
+    }
+
+    /// Records a blink on the next advance, with the bit it revealed.
+    pub fn add_blink(&mut self, bit: bool) {
+        let mut equation = 0u128;
+        for (i, rng) in self.basis.iter_mut().enumerate() {
+            if rng.next() & 1 == 1 {
+                equation |= 1 << i;
+            }
+        }
+
+        self.equations.push((equation, bit));
+        self.advances += 1;
+    }
+
+    /// Records a blink after `skipped` advances without one, with the bit it revealed.
+    pub fn add_blink_after(&mut self, skipped: u32, bit: bool) {
+        for _ in 0..skipped {
+            self.basis.iter_mut().for_each(|rng| {
+                rng.next();
+            });
+        }
+        self.advances += skipped as u64;
+        self.add_blink(bit);
+    }
+
+    /// Number of advances recorded so far, including those without a blink.
+    pub fn get_advances(&self) -> f64 {
+        self.advances as f64
+    }
+
+    /// Solves the current state, returning both halves in hex.
+    /// Fails if there aren't enough blinks, or if they contradict each other.
+    pub fn solve_current(&self) -> Result<js_sys::Array, JsValue> {
+        let (s0, s1) = self.solve().map_err(|e| JsValue::from(e.to_string()))?;
+
+        let halves = [format!("{:016x}", s0), format!("{:016x}", s1)];
+        Ok(halves.iter().map(JsValue::from).collect())
+    }
+}
+
+impl Default for BlinkSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlinkSolver {
+    /// Solves the state before the first blink.
+    pub fn solve_initial(&self) -> Result<(u64, u64), BlinkError> {
+        // Gaussian elimination over GF(2), keeping one pivot row per state bit.
+        let mut pivots: Vec<Option<(u128, bool)>> = vec![None; STATE_BITS];
+        for &(mut equation, mut bit) in self.equations.iter() {
+            while equation != 0 {
+                let lead = STATE_BITS - 1 - equation.leading_zeros() as usize;
+                match pivots[lead] {
+                    Some((pivot, pivot_bit)) => {
+                        equation ^= pivot;
+                        bit ^= pivot_bit;
+                    }
+                    None => {
+                        pivots[lead] = Some((equation, bit));
+                        break;
+                    }
+                }
+            }
+            if equation == 0 && bit {
+                return Err(BlinkError::Inconsistent);
+            }
+        }
+
+        let unknown = pivots.iter().filter(|p| p.is_none()).count();
+        if unknown > 0 {
+            return Err(BlinkError::Ambiguous(unknown as u32));
+        }
+
+        // Back substitute from the lowest bit up, since each pivot only involves lower bits.
+        let mut state = 0u128;
+        for (i, pivot) in pivots.iter().enumerate() {
+            let (equation, bit) = pivot.unwrap();
+            let rest = (equation & state).count_ones() % 2 == 1;
+            if bit ^ rest {
+                state |= 1 << i;
+            }
+        }
+
+        Ok((state as u64, (state >> 64) as u64))
+    }
+
+    /// Solves the state after every advance recorded, ready to generate spawns from.
+    pub fn solve(&self) -> Result<(u64, u64), BlinkError> {
+        let (s0, s1) = self.solve_initial()?;
+        let mut rng = Rng::from_state(s0, s1);
+        for _ in 0..self.advances {
+            rng.next();
+        }
+        Ok(rng.get_state())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const STATE: (u64, u64) = (0x5e5c928d61792fed, 0xed608999e1410aa9);
+
+    /// Records blinks from the real state, skipping advances in a fixed pattern.
+    fn record(count: usize) -> (BlinkSolver, Rng) {
+        let mut rng = Rng::from_state(STATE.0, STATE.1);
+        let mut solver = BlinkSolver::new();
+        for i in 0..count {
+            let skipped = (i % 5) as u32;
+            for _ in 0..skipped {
+                rng.next();
+            }
+            solver.add_blink_after(skipped, rng.next() & 1 == 1);
+        }
+        (solver, rng)
+    }
+
+    #[test]
+    fn test_solve() {
+        let (solver, rng) = record(200);
+        assert_eq!(solver.solve_initial(), Ok(STATE));
+        assert_eq!(solver.solve(), Ok(rng.get_state()));
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        let (solver, _) = record(100);
+        assert_eq!(solver.solve(), Err(BlinkError::Ambiguous(28)));
+        assert_eq!(BlinkSolver::new().solve(), Err(BlinkError::Ambiguous(128)));
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        let (mut solver, _) = record(200);
+        let last = solver.equations.last_mut().unwrap();
+        last.1 = !last.1;
+        assert_eq!(solver.solve(), Err(BlinkError::Inconsistent));
+    }
+}
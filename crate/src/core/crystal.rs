@@ -0,0 +1,312 @@
+//! Dynamax Crystal raids, loaded from JSON dumps of the crystal encounter table.
+//!
+//! Each crystal always spawns the same raid from the same seed, so its frame is fixed apart from
+//! the PID, which depends on the player's TID and SID.
+//!
+//! The expected format is:
+//!
+//! ```json
+//! {
+//!   "Crystals": [
+//!     {
+//!       "Name": "★And458",
+//!       "Seed": "0xc816c270fd1cd8fd",
+//!       "Species": 678,
+//!       "AltForm": 0,
+//!       "FlawlessIVs": 3,
+//!       "ShinyFlag": 1,
+//!       "Ability": 3,
+//!       "Gender": 2,
+//!       "IsGigantamax": false,
+//!       "Stars": 3
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `Seed` is in hex, since JSON numbers can't hold every 64-bit seed. `ShinyFlag`, `Ability` and
+//! `Gender` use the game's encodings, as in `ShinyPool`, `AbilityPool` and `GenderPool`.
+use super::dump::{validate_raid_entry, EntryError};
+use super::frame::{Frame, FrameGenerator, FrameResult};
+use super::raid::Raid;
+use super::trainer::Trainer;
+use serde::Deserialize;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CrystalDump {
+    crystals: Vec<CrystalEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CrystalEntry {
+    name: String,
+    seed: String,
+    species: u32,
+    alt_form: u8,
+    #[serde(rename = "FlawlessIVs")]
+    flawless_ivs: u8,
+    shiny_flag: u8,
+    ability: u8,
+    gender: u8,
+    is_gigantamax: bool,
+    stars: u8,
+}
+
+/// Reasons a crystal dump could not be loaded.
+#[derive(Debug)]
+pub enum CrystalError {
+    /// The dump is not valid JSON, or is missing fields.
+    Json(serde_json::Error),
+    /// A crystal's seed is not a 64-bit hex number.
+    InvalidSeed(String),
+    /// An entry can't be turned into a mon.
+    Entry(EntryError),
+    InvalidStars(u8),
+}
+
+impl fmt::Display for CrystalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrystalError::Json(e) => write!(f, "Malformed crystal dump: {}", e),
+            CrystalError::InvalidSeed(s) => write!(f, "Invalid seed: {}", s),
+            CrystalError::Entry(e) => write!(f, "{}", e),
+            CrystalError::InvalidStars(n) => write!(f, "Invalid star rating: {}", n),
+        }
+    }
+}
+
+impl From<serde_json::Error> for CrystalError {
+    fn from(e: serde_json::Error) -> Self {
+        CrystalError::Json(e)
+    }
+}
+
+impl From<EntryError> for CrystalError {
+    fn from(e: EntryError) -> Self {
+        CrystalError::Entry(e)
+    }
+}
+
+/// Loads the crystal catalogue from a JSON dump.
+pub fn load_crystals(json: &str) -> Result<CrystalTable, CrystalError> {
+    let dump: CrystalDump = serde_json::from_str(json)?;
+    let crystals = dump
+        .crystals
+        .into_iter()
+        .map(|entry| {
+            validate_entry(&entry)?;
+            let digits = entry.seed.trim_start_matches("0x");
+            let seed = u64::from_str_radix(digits, 16)
+                .map_err(|_| CrystalError::InvalidSeed(entry.seed.clone()))?;
+            let raid = Raid::new(
+                entry.species,
+                entry.alt_form,
+                entry.flawless_ivs,
+                entry.is_gigantamax,
+                entry.ability,
+                entry.gender,
+                entry.shiny_flag,
+                entry.stars,
+            );
+            Ok(Crystal::new(entry.name, seed, raid))
+        })
+        .collect::<Result<Vec<_>, CrystalError>>()?;
+
+    Ok(CrystalTable::new(crystals))
+}
+
+/// Checks that an entry can be turned into a raid.
+fn validate_entry(entry: &CrystalEntry) -> Result<(), CrystalError> {
+    validate_raid_entry(
+        entry.species,
+        entry.alt_form,
+        entry.flawless_ivs,
+        entry.shiny_flag,
+        entry.ability,
+        entry.gender,
+    )?;
+    if !(1..=5).contains(&entry.stars) {
+        return Err(CrystalError::InvalidStars(entry.stars));
+    }
+    Ok(())
+}
+
+/// A Dynamax Crystal, with the raid it spawns and the seed it spawns it from.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Crystal {
+    name: String,
+    seed: u64,
+    raid: Raid,
+}
+
+impl Crystal {
+    pub fn new(name: String, seed: u64, raid: Raid) -> Self {
+        Crystal { name, seed, raid }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the frame the crystal always spawns.
+    pub fn get_frame(&self) -> Frame {
+        match FrameGenerator::new(self.raid, self.seed).next() {
+            Some(FrameResult::Pass(frame)) => frame,
+            // Generators without a filter pass every frame.
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the crystal's frame along with its PID for the given trainer.
+    pub fn get_player_frame(&self, trainer: &Trainer) -> CrystalFrame {
+        let frame = self.get_frame();
+        CrystalFrame {
+            raid: self.raid,
+            pid: frame.get_pid(trainer),
+            frame,
+        }
+    }
+}
+
+/// A crystal's frame, as seen by a particular player.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct CrystalFrame {
+    pub raid: Raid,
+    pub frame: Frame,
+    /// PID for the trainer's TID and SID.
+    pub pid: u32,
+}
+
+/// The crystal catalogue.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CrystalTable {
+    crystals: Vec<Crystal>,
+}
+
+#[wasm_bindgen]
+impl CrystalTable {
+    /// Returns the names of every crystal in the catalogue.
+    pub fn get_names(&self) -> js_sys::Array {
+        self.crystals
+            .iter()
+            .map(|c| JsValue::from(c.get_name()))
+            .collect()
+    }
+
+    /// Returns the frame a given crystal spawns for a trainer.
+    pub fn get_frame(&self, name: &str, trainer: &Trainer) -> Option<CrystalFrame> {
+        self.get_crystal(name).map(|c| c.get_player_frame(trainer))
+    }
+
+    /// Lists the frame every crystal spawns for a trainer, in catalogue order.
+    pub fn list_player_frames(&self, trainer: &Trainer) -> js_sys::Array {
+        self.list_frames(trainer)
+            .into_iter()
+            .map(JsValue::from)
+            .collect()
+    }
+}
+
+impl CrystalTable {
+    pub fn new(crystals: Vec<Crystal>) -> Self {
+        CrystalTable { crystals }
+    }
+
+    pub fn get_crystals(&self) -> &[Crystal] {
+        &self.crystals
+    }
+
+    /// Returns the crystal with the given name, e.g. "★And458".
+    pub fn get_crystal(&self, name: &str) -> Option<&Crystal> {
+        self.crystals.iter().find(|c| c.name == name)
+    }
+
+    /// Lists every crystal's frame for the given trainer.
+    pub fn list_frames(&self, trainer: &Trainer) -> Vec<CrystalFrame> {
+        self.crystals
+            .iter()
+            .map(|c| c.get_player_frame(trainer))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::mon::{Ability, Gender, IVs, Nature, Shininess};
+    use super::*;
+
+    // Meowstic's seed and raid from den 2 in Shield, whose frame is known.
+    const CRYSTALS: &str = r#"{
+        "Crystals": [
+            {
+                "Name": "Test",
+                "Seed": "0xc816c270fd1cd8fd",
+                "Species": 678,
+                "AltForm": 0,
+                "FlawlessIVs": 3,
+                "ShinyFlag": 0,
+                "Ability": 3,
+                "Gender": 2,
+                "IsGigantamax": false,
+                "Stars": 3
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_load_crystals() {
+        let table = load_crystals(CRYSTALS).unwrap();
+        let crystal = table.get_crystal("Test").unwrap();
+        assert_eq!(table.get_crystal("Missing"), None);
+
+        let frame = crystal.get_frame();
+        assert_eq!(frame.shiny, Shininess::None);
+        assert_eq!(frame.ivs, IVs(31, 21, 12, 31, 15, 31));
+        assert_eq!(frame.nature, Nature::Brave);
+        assert_eq!(frame.ability, Ability::First);
+        assert_eq!(frame.gender, Gender::Female);
+
+        // The frame doesn't depend on the player, only the PID does.
+        let frames = table.list_frames(&Trainer::new(57649, 60914));
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].frame, frame);
+        assert_eq!(frames[0].raid, crystal.raid);
+        assert_eq!(table.list_frames(&Trainer::new(0, 0))[0].frame, frame);
+    }
+
+    #[test]
+    fn test_load_crystals_errors() {
+        assert!(matches!(
+            load_crystals(&CRYSTALS.replace("0xc816c270fd1cd8fd", "nope")),
+            Err(CrystalError::InvalidSeed(_))
+        ));
+        assert!(matches!(
+            load_crystals(&CRYSTALS.replace(r#""Stars": 3"#, r#""Stars": 0"#)),
+            Err(CrystalError::InvalidStars(0))
+        ));
+        assert!(matches!(
+            load_crystals(&CRYSTALS.replace("678", "9999")),
+            Err(CrystalError::Entry(EntryError::Species { .. }))
+        ));
+        assert!(matches!(load_crystals("{}"), Err(CrystalError::Json(_))));
+    }
+
+    #[test]
+    fn test_shiny_locked_pids() {
+        let table =
+            load_crystals(&CRYSTALS.replace(r#""ShinyFlag": 0"#, r#""ShinyFlag": 1"#)).unwrap();
+        let crystal = table.get_crystal("Test").unwrap();
+
+        // Shiny-locked PIDs are never shiny for the player.
+        for &(tid, sid) in [(57649, 60914), (12345, 54321), (0, 0)].iter() {
+            let trainer = Trainer::new(tid, sid);
+            assert!(!trainer.is_shiny(crystal.get_player_frame(&trainer).pid));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Den encounter tables.
+use super::raid::Raid;
+use wasm_bindgen::prelude::*;
+
+/// Which game the den tables should be read from.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum GameVersion {
+    Sword = 0,
+    Shield = 1,
+}
+
+/// A single entry in a den's encounter table.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct DenEncounter {
+    species: u32,
+    alt_form: u8,
+    min_flawless_ivs: u8,
+    ability_pool: u8,
+    gender_pool: u8,
+    is_gmax: bool,
+    /// Which star levels the encounter appears at, from 1★ to 5★.
+    stars: [bool; 5],
+}
+
+impl DenEncounter {
+    pub fn new(
+        species: u32,
+        alt_form: u8,
+        min_flawless_ivs: u8,
+        ability_pool: u8,
+        gender_pool: u8,
+        is_gmax: bool,
+        stars: [bool; 5],
+    ) -> Self {
+        DenEncounter {
+            species,
+            alt_form,
+            min_flawless_ivs,
+            ability_pool,
+            gender_pool,
+            is_gmax,
+            stars,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl DenEncounter {
+    pub fn get_species(&self) -> u32 {
+        self.species
+    }
+
+    pub fn get_alt_form(&self) -> u8 {
+        self.alt_form
+    }
+
+    pub fn get_min_flawless_ivs(&self) -> u8 {
+        self.min_flawless_ivs
+    }
+
+    pub fn get_ability_pool(&self) -> u8 {
+        self.ability_pool
+    }
+
+    pub fn get_gender_pool(&self) -> u8 {
+        self.gender_pool
+    }
+
+    pub fn is_gmax(&self) -> bool {
+        self.is_gmax
+    }
+
+    /// Returns true if the encounter appears at the given star rating (1-5).
+    pub fn has_stars(&self, stars: u8) -> bool {
+        (1..=5).contains(&stars) && self.stars[stars as usize - 1]
+    }
+
+    /// Returns the highest star rating the encounter appears at.
+    pub fn get_max_stars(&self) -> Option<u8> {
+        (1..=5).rev().find(|&stars| self.has_stars(stars))
+    }
+
+    /// Creates a randomly-shiny raid for the encounter at the given star rating.
+    pub fn create_raid(&self, stars: u8) -> Raid {
+        Raid::new(
+            self.species,
+            self.alt_form,
+            self.min_flawless_ivs,
+            self.is_gmax,
+            self.ability_pool,
+            self.gender_pool,
+            0,
+            stars,
+        )
+    }
+}
+
+/// A den, with separate encounter tables for Sword and Shield.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Den {
+    /// Nest hash identifying the den's encounter table.
+    hash: u64,
+    sword: Vec<DenEncounter>,
+    shield: Vec<DenEncounter>,
+}
+
+impl Den {
+    pub fn new(hash: u64, sword: Vec<DenEncounter>, shield: Vec<DenEncounter>) -> Self {
+        Den {
+            hash,
+            sword,
+            shield,
+        }
+    }
+
+    /// Returns the encounter table for the given game.
+    pub fn get_encounters(&self, version: GameVersion) -> &[DenEncounter] {
+        match version {
+            GameVersion::Sword => &self.sword,
+            GameVersion::Shield => &self.shield,
+        }
+    }
+}
+
+/// All dens, indexed by den number. Den 0 is a placeholder with no encounters.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DenTable {
+    dens: Vec<Den>,
+}
+
+impl DenTable {
+    pub fn new(dens: Vec<Den>) -> Self {
+        DenTable { dens }
+    }
+
+    /// Returns the den with the given number.
+    pub fn get_den(&self, den: usize) -> Option<&Den> {
+        self.dens.get(den).filter(|_| den > 0)
+    }
+
+    /// Returns the number of the den with the given nest hash.
+    pub fn get_den_by_hash(&self, hash: u64) -> Option<usize> {
+        self.dens
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find(|(_, den)| den.hash == hash)
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the number of dens, excluding the placeholder.
+    pub fn get_den_count(&self) -> usize {
+        self.dens.len().saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::den_data::DEN_DATA;
+    use super::*;
+
+    #[test]
+    fn test_den_lookup() {
+        assert_eq!(DEN_DATA.get_den_count(), 197);
+        assert_eq!(DEN_DATA.get_den(0), None);
+
+        let den = DEN_DATA.get_den(166).unwrap();
+        assert_eq!(den.get_encounters(GameVersion::Sword).len(), 12);
+        assert_eq!(DEN_DATA.get_den_by_hash(538718828553644350), Some(166));
+        assert_eq!(DEN_DATA.get_den_by_hash(0), None);
+    }
+
+    #[test]
+    fn test_encounter_stars() {
+        // Den 166, Sword: 3-5* Audino.
+        let encounter = DEN_DATA
+            .get_den(166)
+            .unwrap()
+            .get_encounters(GameVersion::Sword)[0];
+        assert!(!encounter.has_stars(2));
+        assert!(encounter.has_stars(3));
+        assert!(encounter.has_stars(5));
+        assert!(!encounter.has_stars(6));
+        assert_eq!(encounter.get_max_stars(), Some(5));
+        assert_eq!(encounter.create_raid(3).get_level_range(), (35, 40));
+    }
+}
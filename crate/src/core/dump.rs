@@ -0,0 +1,99 @@
+//! Checks shared by the JSON dump loaders, so every dump rejects the same bad entries.
+use super::super::personal_data::get_personal_info;
+use std::fmt;
+
+/// Reasons an entry in a dump can't be turned into a mon.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum EntryError {
+    /// The species and form have no personal data.
+    Species {
+        species: u32,
+        alt_form: u8,
+    },
+    FlawlessIVs(u8),
+    ShinyFlag(u8),
+    Ability(u8),
+    Gender(u8),
+}
+
+impl fmt::Display for EntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EntryError::Species { species, alt_form } => {
+                write!(f, "Invalid species: {} (form {})", species, alt_form)
+            }
+            EntryError::FlawlessIVs(n) => write!(f, "Invalid flawless IV count: {}", n),
+            EntryError::ShinyFlag(n) => write!(f, "Invalid shiny flag: {}", n),
+            EntryError::Ability(n) => write!(f, "Invalid ability type: {}", n),
+            EntryError::Gender(n) => write!(f, "Invalid gender type: {}", n),
+        }
+    }
+}
+
+/// Checks that a species and form exist in the games.
+pub fn validate_species(species: u32, alt_form: u8) -> Result<(), EntryError> {
+    match get_personal_info(species as usize, alt_form as usize) {
+        Some(pi) if species > 0 && pi.is_included() => Ok(()),
+        _ => Err(EntryError::Species { species, alt_form }),
+    }
+}
+
+/// Checks that a raid entry's fields use the game's encodings, as in `ShinyPool`, `AbilityPool`
+/// and `GenderPool`.
+pub fn validate_raid_entry(
+    species: u32,
+    alt_form: u8,
+    flawless_ivs: u8,
+    shiny_flag: u8,
+    ability: u8,
+    gender: u8,
+) -> Result<(), EntryError> {
+    validate_species(species, alt_form)?;
+    if flawless_ivs > 6 {
+        return Err(EntryError::FlawlessIVs(flawless_ivs));
+    }
+    if shiny_flag > 2 {
+        return Err(EntryError::ShinyFlag(shiny_flag));
+    }
+    if ability > 4 {
+        return Err(EntryError::Ability(ability));
+    }
+    if gender > 3 {
+        return Err(EntryError::Gender(gender));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_raid_entry() {
+        // Pikachu, with every field at its largest valid value.
+        assert_eq!(validate_raid_entry(25, 0, 6, 2, 4, 3), Ok(()));
+        assert_eq!(
+            validate_raid_entry(0, 0, 4, 0, 4, 0),
+            Err(EntryError::Species {
+                species: 0,
+                alt_form: 0
+            })
+        );
+        assert_eq!(
+            validate_raid_entry(25, 0, 7, 0, 4, 0),
+            Err(EntryError::FlawlessIVs(7))
+        );
+        assert_eq!(
+            validate_raid_entry(25, 0, 4, 3, 4, 0),
+            Err(EntryError::ShinyFlag(3))
+        );
+        assert_eq!(
+            validate_raid_entry(25, 0, 4, 0, 5, 0),
+            Err(EntryError::Ability(5))
+        );
+        assert_eq!(
+            validate_raid_entry(25, 0, 4, 0, 4, 4),
+            Err(EntryError::Gender(4))
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Event (Wild Area News) den tables, loaded from JSON dumps of the nest event encounter table.
+//!
+//! The expected format is:
+//!
+//! ```json
+//! {
+//!   "Tables": [
+//!     {
+//!       "GameVersion": 1,
+//!       "Entries": [
+//!         {
+//!           "Species": 25,
+//!           "AltForm": 0,
+//!           "FlawlessIVs": 4,
+//!           "ShinyFlag": 0,
+//!           "Ability": 4,
+//!           "Gender": 0,
+//!           "IsGigantamax": true,
+//!           "Probabilities": [0, 0, 0, 35, 35]
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `GameVersion` is 1 for Sword and 2 for Shield. `ShinyFlag`, `Ability` and `Gender` use the
+//! game's encodings, as in `ShinyPool`, `AbilityPool` and `GenderPool`. `Probabilities` holds the
+//! percent chance of the entry at each star level, from 1★ to 5★.
+use super::den::GameVersion;
+use super::dump::{validate_raid_entry, EntryError};
+use super::raid::Raid;
+use serde::Deserialize;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EventDump {
+    tables: Vec<EventTable>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EventTable {
+    game_version: u8,
+    entries: Vec<EventEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EventEntry {
+    species: u32,
+    alt_form: u8,
+    #[serde(rename = "FlawlessIVs")]
+    flawless_ivs: u8,
+    shiny_flag: u8,
+    ability: u8,
+    gender: u8,
+    is_gigantamax: bool,
+    probabilities: [u8; 5],
+}
+
+/// Reasons an event dump could not be loaded.
+#[derive(Debug)]
+pub enum EventError {
+    /// The dump is not valid JSON, or is missing fields.
+    Json(serde_json::Error),
+    InvalidGameVersion(u8),
+    /// An entry can't be turned into a mon.
+    Entry(EntryError),
+    /// A table's probabilities at some star level are neither all zero nor sum to 100.
+    InvalidProbabilities {
+        table: usize,
+        stars: u8,
+        total: u32,
+    },
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventError::Json(e) => write!(f, "Malformed event dump: {}", e),
+            EventError::InvalidGameVersion(n) => write!(f, "Invalid game version: {}", n),
+            EventError::Entry(e) => write!(f, "{}", e),
+            EventError::InvalidProbabilities {
+                table,
+                stars,
+                total,
+            } => write!(
+                f,
+                "Probabilities for table {} at {}★ sum to {}, not 100",
+                table, stars, total
+            ),
+        }
+    }
+}
+
+impl From<serde_json::Error> for EventError {
+    fn from(e: serde_json::Error) -> Self {
+        EventError::Json(e)
+    }
+}
+
+impl From<EntryError> for EventError {
+    fn from(e: EntryError) -> Self {
+        EventError::Entry(e)
+    }
+}
+
+/// An event raid at a single star level.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct EventRaid {
+    pub version: GameVersion,
+    /// Index of the entry within its table.
+    pub entry: usize,
+    pub raid: Raid,
+    /// Percent chance of the entry being picked at the raid's star level.
+    pub probability: u8,
+}
+
+/// Loads event raids from a JSON dump, with one raid for each star level an entry appears at.
+pub fn load_event_raids(json: &str) -> Result<Vec<EventRaid>, EventError> {
+    let dump: EventDump = serde_json::from_str(json)?;
+    let mut raids = Vec::new();
+
+    for (table_index, table) in dump.tables.iter().enumerate() {
+        let version = match table.game_version {
+            1 => GameVersion::Sword,
+            2 => GameVersion::Shield,
+            n => return Err(EventError::InvalidGameVersion(n)),
+        };
+
+        for stars in 1..=5 {
+            let total: u32 = table
+                .entries
+                .iter()
+                .map(|e| e.probabilities[stars as usize - 1] as u32)
+                .sum();
+            if total != 0 && total != 100 {
+                return Err(EventError::InvalidProbabilities {
+                    table: table_index,
+                    stars,
+                    total,
+                });
+            }
+        }
+
+        for (entry_index, entry) in table.entries.iter().enumerate() {
+            validate_raid_entry(
+                entry.species,
+                entry.alt_form,
+                entry.flawless_ivs,
+                entry.shiny_flag,
+                entry.ability,
+                entry.gender,
+            )?;
+            for stars in 1..=5 {
+                let probability = entry.probabilities[stars as usize - 1];
+                if probability == 0 {
+                    continue;
+                }
+                raids.push(EventRaid {
+                    version,
+                    entry: entry_index,
+                    raid: Raid::new(
+                        entry.species,
+                        entry.alt_form,
+                        entry.flawless_ivs,
+                        entry.is_gigantamax,
+                        entry.ability,
+                        entry.gender,
+                        entry.shiny_flag,
+                        stars,
+                    ),
+                    probability,
+                });
+            }
+        }
+    }
+
+    Ok(raids)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::mon::Ability;
+    use super::super::raid::{AbilityPool, ShinyPool};
+    use super::*;
+
+    const EVENT: &str = r#"{
+        "Tables": [
+            {
+                "GameVersion": 1,
+                "Entries": [
+                    {
+                        "Species": 25,
+                        "AltForm": 0,
+                        "FlawlessIVs": 4,
+                        "ShinyFlag": 2,
+                        "Ability": 2,
+                        "Gender": 0,
+                        "IsGigantamax": true,
+                        "Probabilities": [0, 0, 0, 100, 40]
+                    },
+                    {
+                        "Species": 133,
+                        "AltForm": 0,
+                        "FlawlessIVs": 5,
+                        "ShinyFlag": 1,
+                        "Ability": 4,
+                        "Gender": 0,
+                        "IsGigantamax": false,
+                        "Probabilities": [0, 0, 0, 0, 60]
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_load_event_raids() {
+        let raids = load_event_raids(EVENT).unwrap();
+        assert_eq!(raids.len(), 3);
+
+        // Forced-shiny, HA-locked G-max Pikachu at 4* and 5*.
+        assert_eq!(raids[0].entry, 0);
+        assert_eq!(raids[0].probability, 100);
+        assert_eq!(raids[0].raid.get_stars(), 4);
+        assert_eq!(raids[0].raid.get_shiny_pool(), ShinyPool::Locked(true));
+        assert_eq!(
+            raids[0].raid.get_ability_pool(),
+            AbilityPool::Locked(Ability::Hidden)
+        );
+        assert_eq!(raids[1].raid.get_stars(), 5);
+        assert_eq!(raids[1].probability, 40);
+
+        // Shiny-locked Eevee at 5*.
+        assert_eq!(raids[2].version, GameVersion::Sword);
+        assert_eq!(raids[2].entry, 1);
+        assert_eq!(raids[2].raid.get_shiny_pool(), ShinyPool::Locked(false));
+        assert_eq!(raids[2].raid.get_ability_pool(), AbilityPool::Random);
+    }
+
+    #[test]
+    fn test_malformed_event_raids() {
+        assert!(matches!(
+            load_event_raids("{\"Tables\": [{}]}"),
+            Err(EventError::Json(_))
+        ));
+        assert!(matches!(
+            load_event_raids(&EVENT.replace("\"Ability\": 2", "\"Ability\": 7")),
+            Err(EventError::Entry(EntryError::Ability(7)))
+        ));
+        assert!(matches!(
+            load_event_raids(&EVENT.replace("\"Species\": 133", "\"Species\": 187")),
+            Err(EventError::Entry(EntryError::Species { species: 187, .. }))
+        ));
+        assert!(matches!(
+            load_event_raids(&EVENT.replace("\"GameVersion\": 1", "\"GameVersion\": 3")),
+            Err(EventError::InvalidGameVersion(3))
+        ));
+        assert!(matches!(
+            load_event_raids(&EVENT.replace("[0, 0, 0, 0, 60]", "[0, 0, 0, 0, 50]")),
+            Err(EventError::InvalidProbabilities {
+                table: 0,
+                stars: 5,
+                total: 90
+            })
+        ));
+    }
+}
@@ -1,5 +1,5 @@
 ///! Filters to apply when searching for a particular frame.
-use super::mon::{Ability, Gender, IVs, Nature, Shininess};
+use super::mon::{Ability, Gender, HeldItem, IVs, Mark, Nature, PersonalityMark, Shininess, Stats};
 use num_traits::ToPrimitive;
 use std::cmp::Ordering;
 use wasm_bindgen::prelude::*;
@@ -184,6 +184,78 @@ impl Filter<IVs> for IVFilter {
     }
 }
 
+/// Specifies bounds for a single computed stat.
+#[wasm_bindgen(inspectable)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct SingleStatFilter {
+    value: u32,
+    direction: RangeDirection,
+}
+
+#[wasm_bindgen]
+impl SingleStatFilter {
+    pub fn new_at_least(value: u32) -> Self {
+        SingleStatFilter {
+            value,
+            direction: RangeDirection::AtLeast,
+        }
+    }
+
+    pub fn new_at_most(value: u32) -> Self {
+        SingleStatFilter {
+            value,
+            direction: RangeDirection::AtMost,
+        }
+    }
+}
+
+impl Filter<u32> for SingleStatFilter {
+    fn test(&self, value: &u32) -> bool {
+        match self.direction {
+            RangeDirection::AtLeast => *value >= self.value,
+            RangeDirection::AtMost => *value <= self.value,
+        }
+    }
+}
+
+/// Specifies bounds on computed stats, at a given level.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct StatFilter {
+    level: u32,
+    hp: Option<SingleStatFilter>,
+    atk: Option<SingleStatFilter>,
+    def: Option<SingleStatFilter>,
+    spa: Option<SingleStatFilter>,
+    spd: Option<SingleStatFilter>,
+    spe: Option<SingleStatFilter>,
+}
+
+impl StatFilter {
+    /// Returns the level at which stats should be computed.
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+}
+
+impl Filter<Stats> for StatFilter {
+    fn test(&self, value: &Stats) -> bool {
+        [
+            (self.hp, value.0),
+            (self.atk, value.1),
+            (self.def, value.2),
+            (self.spa, value.3),
+            (self.spd, value.4),
+            (self.spe, value.5),
+        ]
+        .iter()
+        .all(|(filter, stat)| match filter {
+            Some(f) => f.test(stat),
+            None => true,
+        })
+    }
+}
+
 /// Specifies shininess.
 #[wasm_bindgen(inspectable)]
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
@@ -201,7 +273,8 @@ impl Filter<Shininess> for ShinyFilter {
     }
 }
 
-/// Specifies ability.
+/// Specifies ability slot.
+/// To filter on a concrete ability ID instead, see `FrameFilter::set_ability_id`.
 #[wasm_bindgen(inspectable)]
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub enum AbilityFilter {
@@ -313,8 +386,10 @@ pub struct FrameFilter {
     pub shiny: Option<ShinyFilter>,
     pub ivs: Option<IVFilter>,
     pub ability: Option<AbilityFilter>,
+    pub ability_id: Option<u16>,
     pub gender: Option<GenderFilter>,
     pub nature: Option<NatureFilter>,
+    pub stats: Option<StatFilter>,
 }
 
 #[wasm_bindgen]
@@ -325,8 +400,10 @@ impl FrameFilter {
             shiny: None,
             ivs: None,
             ability: None,
+            ability_id: None,
             gender: None,
             nature: None,
+            stats: None,
         }
     }
 
@@ -354,6 +431,12 @@ impl FrameFilter {
         *self
     }
 
+    /// Filters on a concrete ability ID, regardless of which slot it occupies.
+    pub fn set_ability_id(&mut self, id: u16) -> Self {
+        self.ability_id = Some(id);
+        *self
+    }
+
     pub fn set_gender(&mut self, filter: GenderFilter) -> Self {
         self.gender = Some(filter);
         *self
@@ -363,6 +446,212 @@ impl FrameFilter {
         self.nature = Some(filter);
         *self
     }
+
+    /// Filters on stats computed at the given level, e.g. "Speed at most 150 at level 100".
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_stats(
+        &mut self,
+        level: u32,
+        hp: Option<SingleStatFilter>,
+        atk: Option<SingleStatFilter>,
+        def: Option<SingleStatFilter>,
+        spa: Option<SingleStatFilter>,
+        spd: Option<SingleStatFilter>,
+        spe: Option<SingleStatFilter>,
+    ) -> Self {
+        self.stats = Some(StatFilter {
+            level,
+            hp,
+            atk,
+            def,
+            spa,
+            spd,
+            spe,
+        });
+        *self
+    }
+}
+
+/// Specifies a kind of mark.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum MarkFilter {
+    Any = 0, // Any mark at all.
+    Rare = 1,
+    Personality = 2, // Any personality mark.
+    Uncommon = 3,
+    Weather = 4, // Any weather mark.
+    Time = 5,    // Any time-of-day mark.
+    Fishing = 6,
+}
+
+impl Filter<Option<Mark>> for MarkFilter {
+    fn test(&self, value: &Option<Mark>) -> bool {
+        matches!(
+            (self, value),
+            (Self::Any, Some(_))
+                | (Self::Rare, Some(Mark::Rare))
+                | (Self::Personality, Some(Mark::Personality(_)))
+                | (Self::Uncommon, Some(Mark::Uncommon))
+                | (Self::Weather, Some(Mark::Weather(_)))
+                | (Self::Time, Some(Mark::Time(_)))
+                | (Self::Fishing, Some(Mark::Fishing))
+        )
+    }
+}
+
+/// Specifies an inclusive range, e.g. of encounter slots or levels.
+#[wasm_bindgen(inspectable)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct RangeFilter {
+    pub min: u32,
+    pub max: u32,
+}
+
+#[wasm_bindgen]
+impl RangeFilter {
+    #[wasm_bindgen(constructor)]
+    pub fn new(min: u32, max: u32) -> Self {
+        RangeFilter { min, max }
+    }
+}
+
+impl Filter<u32> for RangeFilter {
+    fn test(&self, value: &u32) -> bool {
+        (self.min..=self.max).contains(value)
+    }
+}
+
+/// Filter aspects of an overworld spawn.
+/// Slot, level, brilliant, species and held item filters never pass static encounters.
+#[wasm_bindgen(inspectable)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct SpawnFilter {
+    pub shiny: Option<ShinyFilter>,
+    pub mark: Option<MarkFilter>,
+    pub personality_mark: Option<PersonalityMark>,
+    pub nature: Option<NatureFilter>,
+    pub ivs: Option<IVFilter>,
+    pub slot: Option<RangeFilter>,
+    pub level: Option<RangeFilter>,
+    pub brilliant: Option<bool>,
+    /// Never passes spawns whose species is unknown.
+    pub species: Option<u32>,
+    pub ability: Option<AbilityFilter>,
+    pub held_item: Option<HeldItem>,
+    /// Never passes spawns whose gender is unknown.
+    pub gender: Option<GenderFilter>,
+    pub height: Option<RangeFilter>,
+    pub weight: Option<RangeFilter>,
+}
+
+#[wasm_bindgen]
+impl SpawnFilter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        SpawnFilter {
+            shiny: None,
+            mark: None,
+            personality_mark: None,
+            nature: None,
+            ivs: None,
+            slot: None,
+            level: None,
+            brilliant: None,
+            species: None,
+            ability: None,
+            held_item: None,
+            gender: None,
+            height: None,
+            weight: None,
+        }
+    }
+
+    pub fn set_shiny(&mut self, filter: ShinyFilter) -> Self {
+        self.shiny = Some(filter);
+        *self
+    }
+
+    pub fn set_mark(&mut self, filter: MarkFilter) -> Self {
+        self.mark = Some(filter);
+        *self
+    }
+
+    /// Filters on one specific personality mark.
+    pub fn set_personality_mark(&mut self, mark: PersonalityMark) -> Self {
+        self.personality_mark = Some(mark);
+        *self
+    }
+
+    pub fn set_nature(&mut self, filter: NatureFilter) -> Self {
+        self.nature = Some(filter);
+        *self
+    }
+
+    pub fn set_ivs(
+        &mut self,
+        hp: Option<SingleIVFilter>,
+        atk: Option<SingleIVFilter>,
+        def: Option<SingleIVFilter>,
+        spa: Option<SingleIVFilter>,
+        spd: Option<SingleIVFilter>,
+        spe: Option<SingleIVFilter>,
+    ) -> Self {
+        self.ivs = Some(IVFilter(hp, atk, def, spa, spd, spe));
+        *self
+    }
+
+    /// Filters on encounter slot rolls (0-99) within the given range, inclusive.
+    pub fn set_slot(&mut self, min: u32, max: u32) -> Self {
+        self.slot = Some(RangeFilter::new(min, max));
+        *self
+    }
+
+    /// Filters on levels within the given range, inclusive.
+    pub fn set_level(&mut self, min: u32, max: u32) -> Self {
+        self.level = Some(RangeFilter::new(min, max));
+        *self
+    }
+
+    /// Filters on the species picked by the spawn's slot.
+    pub fn set_species(&mut self, species: u32) -> Self {
+        self.species = Some(species);
+        *self
+    }
+
+    /// Filters on whether the spawn has a brilliant aura.
+    pub fn set_brilliant(&mut self, brilliant: bool) -> Self {
+        self.brilliant = Some(brilliant);
+        *self
+    }
+
+    pub fn set_ability(&mut self, filter: AbilityFilter) -> Self {
+        self.ability = Some(filter);
+        *self
+    }
+
+    /// Filters on the held item slot. Static encounters never pass.
+    pub fn set_held_item(&mut self, item: HeldItem) -> Self {
+        self.held_item = Some(item);
+        *self
+    }
+
+    pub fn set_gender(&mut self, filter: GenderFilter) -> Self {
+        self.gender = Some(filter);
+        *self
+    }
+
+    /// Filters on height scalars within the given range, inclusive.
+    pub fn set_height(&mut self, min: u32, max: u32) -> Self {
+        self.height = Some(RangeFilter::new(min, max));
+        *self
+    }
+
+    /// Filters on weight scalars within the given range, inclusive.
+    pub fn set_weight(&mut self, min: u32, max: u32) -> Self {
+        self.weight = Some(RangeFilter::new(min, max));
+        *self
+    }
 }
 
 #[cfg(test)]
@@ -450,6 +739,30 @@ mod test {
         assert!(!NatureFilter::from_natures(vec![Nature::Hardy]).test(&Nature::Timid));
     }
 
+    #[test]
+    fn test_stat_filter() {
+        let f = FrameFilter::new()
+            .set_stats(
+                100,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(SingleStatFilter::new_at_most(200)),
+            )
+            .stats
+            .unwrap();
+        assert_eq!(f.get_level(), 100);
+        assert!(f.test(&Stats(357, 296, 226, 176, 206, 200)));
+        assert!(f.test(&Stats(357, 296, 226, 176, 206, 100)));
+        assert!(!f.test(&Stats(357, 296, 226, 176, 206, 264)));
+
+        let single = SingleStatFilter::new_at_least(150);
+        assert!(single.test(&150));
+        assert!(!single.test(&149));
+    }
+
     #[test]
     fn test_nature_disallows_all_by_default() {
         assert!(!NatureFilter::from_vec(Vec::new()).test(&Nature::Timid));
@@ -493,9 +806,30 @@ mod test {
                     })
                 )),
                 ability: Some(AbilityFilter::Second),
+                ability_id: None,
                 gender: Some(GenderFilter::Male),
-                nature: Some(natures)
+                nature: Some(natures),
+                stats: None,
             }
         )
     }
+
+    #[test]
+    fn test_mark_filter() {
+        let personality = Some(Mark::Personality(PersonalityMark::Rowdy));
+        assert!(MarkFilter::Any.test(&personality));
+        assert!(MarkFilter::Personality.test(&personality));
+        assert!(!MarkFilter::Rare.test(&personality));
+        assert!(MarkFilter::Rare.test(&Some(Mark::Rare)));
+        assert!(!MarkFilter::Any.test(&None));
+    }
+
+    #[test]
+    fn test_range_filter() {
+        let filter = RangeFilter::new(20, 29);
+        assert!(!filter.test(&19));
+        assert!(filter.test(&20));
+        assert!(filter.test(&29));
+        assert!(!filter.test(&30));
+    }
 }
@@ -1,8 +1,10 @@
 ///! Frame data.
+use super::super::ability_data::get_ability_name;
 use super::filter::{Filter, FrameFilter};
-use super::mon::{get_toxtricity_nature, Ability, Gender, IVs, Nature, Shininess};
+use super::mon::{get_toxtricity_nature, Ability, Gender, IVs, Nature, Shininess, Stats};
 use super::raid::{AbilityPool, GenderPool, Raid, ShinyPool};
 use super::rng::Rng;
+use super::trainer::Trainer;
 use num_traits::FromPrimitive;
 use wasm_bindgen::prelude::*;
 
@@ -36,6 +38,64 @@ impl Frame {
     pub fn get_seed(&self) -> Seed {
         Seed::from_u64(self.seed)
     }
+
+    /// Computes the frame's final PID for the given trainer.
+    ///
+    /// The game rolls shininess against a temporary TID/SID, then fixes up the PID so that
+    /// it has the same shininess against the trainer's real TSV.
+    pub fn get_pid(&self, trainer: &Trainer) -> u32 {
+        let mut rng = Rng::new(self.seed);
+        let _ = rng.next_int(u32::MAX); // EC.
+        let _ = rng.next_int(u32::MAX); // Temporary TID/SID.
+        let pid = rng.next_int(u32::MAX);
+
+        let base = trainer.get_shiny_xor_base() as u32;
+        let lsbs = pid & 0xffff;
+        match (self.shiny, trainer.get_shininess(pid)) {
+            // Make the PID unshiny for the trainer.
+            (Shininess::None, Shininess::None) => pid,
+            (Shininess::None, _) => pid ^ 0x1000_0000,
+            // Make the PID shiny for the trainer, preserving star vs. square.
+            (Shininess::Star, Shininess::Star) | (Shininess::Square, Shininess::Square) => pid,
+            (Shininess::Star, _) => ((lsbs ^ base ^ 1) << 16) | lsbs,
+            (Shininess::Square, _) => ((lsbs ^ base) << 16) | lsbs,
+        }
+    }
+
+    /// Returns the ID of the frame's ability, resolving its slot against the raid mon's abilities.
+    pub fn get_ability_id(&self, raid: &Raid) -> Option<u16> {
+        raid.get_abilities()
+            .map(|abilities| abilities.get(self.ability))
+    }
+
+    /// Returns the English name of the frame's ability.
+    pub fn get_ability_name(&self, raid: &Raid) -> Option<String> {
+        self.get_ability_id(raid).and_then(get_ability_name)
+    }
+
+    /// Returns true if the frame's ability also occupies another slot,
+    /// in which case filtering on the slot alone is misleading.
+    pub fn is_ability_shared(&self, raid: &Raid) -> bool {
+        raid.get_abilities()
+            .map(|abilities| abilities.is_shared(self.ability))
+            .unwrap_or(false)
+    }
+
+    /// Computes the caught mon's stats at the given level.
+    pub fn get_stats(&self, raid: &Raid, level: u32) -> Option<Stats> {
+        raid.get_base_stats()
+            .map(|base| Stats::calculate(raid.get_species(), &base, &self.ivs, self.nature, level))
+    }
+
+    /// Computes the caught mon's stats at the lowest level for the raid's star rating.
+    pub fn get_min_stats(&self, raid: &Raid) -> Option<Stats> {
+        self.get_stats(raid, raid.get_level_range().0)
+    }
+
+    /// Computes the caught mon's stats at the highest level for the raid's star rating.
+    pub fn get_max_stats(&self, raid: &Raid) -> Option<Stats> {
+        self.get_stats(raid, raid.get_level_range().1)
+    }
 }
 
 /// Describes the result of stepping one frame.
@@ -122,6 +182,12 @@ impl FrameGenerator {
                 return FrameResult::Fail;
             }
         }
+        if let Some(id) = filter.ability_id {
+            match self.raid.get_abilities() {
+                Some(abilities) if abilities.get(ability) == id => {}
+                _ => return FrameResult::Fail,
+            }
+        }
         let gender = self.get_gender();
         if let Some(f) = filter.gender {
             if !f.test(&gender) {
@@ -134,6 +200,16 @@ impl FrameGenerator {
                 return FrameResult::Fail;
             }
         }
+        if let Some(f) = filter.stats {
+            // Mons without personal data can't have their stats computed, so never pass.
+            let stats = self.raid.get_base_stats().map(|base| {
+                Stats::calculate(self.raid.get_species(), &base, &ivs, nature, f.get_level())
+            });
+            match stats {
+                Some(stats) if f.test(&stats) => {}
+                _ => return FrameResult::Fail,
+            }
+        }
 
         FrameResult::Pass(Frame {
             seed: self.seed,
@@ -296,7 +372,9 @@ impl Iterator for FrameGenerator {
 
 #[cfg(test)]
 mod test {
-    use super::super::filter::{IVJudgment, NatureFilter, ShinyFilter, SingleIVFilter};
+    use super::super::filter::{
+        IVJudgment, NatureFilter, ShinyFilter, SingleIVFilter, SingleStatFilter,
+    };
     use super::*;
 
     #[test]
@@ -311,6 +389,8 @@ mod test {
                     false, // Not G-max.
                     3,     // Random ability, no HA.
                     2,     // Gender-locked female.
+                    0,     // Random shiny.
+                    3,     // 3★.
                 ),
                 0xc816c270fd1cd8fd
             )
@@ -339,6 +419,8 @@ mod test {
                     false, // Not G-max.
                     3,     // Random ability, no HA.
                     0,     // Random gender.
+                    0,     // Random shiny.
+                    1,     // 1★.
                 ),
                 0x4ab973e61fba4358
             )
@@ -367,6 +449,8 @@ mod test {
                     false, // Not G-max.
                     2,     // HA-locked.
                     0,     // Random gender.
+                    0,     // Random shiny.
+                    4,     // 4★.
                 ),
                 0x775b846f76f1b25d
             )
@@ -382,6 +466,87 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_shiny_locked() {
+        // Den 100, but never shiny.
+        let f = FrameGenerator::new(
+            Raid::new(
+                40,    // Wigglytuff.
+                0,     // Alt form: N/A.
+                4,     // Guaranteed flawless IVs.
+                false, // Not G-max.
+                2,     // HA-locked.
+                0,     // Random gender.
+                1,     // Shiny-locked.
+                4,     // 4★.
+            ),
+            0x775b846f76f1b25d,
+        );
+
+        // None of the frames are shiny, even those that would be with a random pool.
+        assert_eq!(
+            f.take(10)
+                .filter_map(FrameResult::to_option)
+                .map(|frame| frame.shiny)
+                .collect::<Vec<Shininess>>(),
+            vec![Shininess::None; 10]
+        );
+    }
+
+    #[test]
+    fn test_shiny_random() {
+        // Den 100, with random shininess.
+        let mut f = FrameGenerator::new(
+            Raid::new(
+                40,    // Wigglytuff.
+                0,     // Alt form: N/A.
+                4,     // Guaranteed flawless IVs.
+                false, // Not G-max.
+                2,     // HA-locked.
+                0,     // Random gender.
+                0,     // Random shininess.
+                4,     // 4★.
+            ),
+            0x775b846f76f1b25d,
+        );
+
+        // The first frame is a star shiny when shininess is random.
+        assert_eq!(
+            f.next()
+                .and_then(FrameResult::to_option)
+                .map(|frame| frame.shiny),
+            Some(Shininess::Star)
+        );
+    }
+
+    #[test]
+    fn test_forced_shiny() {
+        // Den 100, but always shiny.
+        let f = FrameGenerator::new(
+            Raid::new(
+                40,    // Wigglytuff.
+                0,     // Alt form: N/A.
+                4,     // Guaranteed flawless IVs.
+                false, // Not G-max.
+                2,     // HA-locked.
+                0,     // Random gender.
+                2,     // Forced shiny.
+                4,     // 4★.
+            ),
+            0x775b846f76f1b25d,
+        );
+
+        // Frames that would be shiny anyway keep their shiny type,
+        // and frames that would not be shiny become square.
+        assert_eq!(
+            f.take(3)
+                .filter_map(FrameResult::to_option)
+                .map(|frame| frame.shiny)
+                .collect::<Vec<Shininess>>(),
+            vec![Shininess::Star, Shininess::Square, Shininess::Square]
+        );
+    }
+
     #[test]
     fn test_multi_frame_ha_locked() {
         // Den 100.
@@ -394,6 +559,8 @@ mod test {
                 false, // Not G-max.
                 2,     // HA-locked.
                 0,     // Random gender.
+                0,     // Random shiny.
+                4,     // 4★.
             ),
             0x775b846f76f1b25d,
         );
@@ -499,6 +666,8 @@ mod test {
                 false, // Not G-max.
                 3,     // Random ability, no HA.
                 0,     // Random gender.
+                0,     // Random shiny.
+                1,     // 1★.
             ),
             0x4ab973e61fba4358,
         );
@@ -604,6 +773,8 @@ mod test {
                 false, // Not G-max.
                 3,     // Random ability, no HA.
                 0,     // Random gender.
+                0,     // Random shiny.
+                1,     // 1★.
             ),
             0x4ab973e61fba4358,
         );
@@ -652,6 +823,170 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_frame_pid() {
+        // Den 100.
+        let raid = Raid::new(
+            40,    // Wigglytuff.
+            0,     // Alt form: N/A.
+            4,     // Guaranteed flawless IVs.
+            false, // Not G-max.
+            2,     // HA-locked.
+            0,     // Random gender.
+            0,     // Random shiny.
+            4,     // 4★.
+        );
+        let frames = FrameGenerator::new(raid, 0x775b846f76f1b25d)
+            .take(2)
+            .filter_map(FrameResult::to_option)
+            .collect::<Vec<Frame>>();
+
+        let trainers = [
+            Trainer::new(57649, 60914),
+            Trainer::new(12345, 54321),
+            Trainer::new(0, 0),
+        ];
+
+        // Star shiny frame stays a star shiny for any trainer.
+        for trainer in trainers.iter() {
+            let pid = frames[0].get_pid(trainer);
+            assert_eq!(trainer.get_shininess(pid), Shininess::Star);
+        }
+
+        // Non-shiny frame stays non-shiny for any trainer.
+        for trainer in trainers.iter() {
+            assert!(!trainer.is_shiny(frames[1].get_pid(trainer)));
+        }
+    }
+
+    #[test]
+    fn test_frame_stats() {
+        // Den 100.
+        let raid = Raid::new(
+            40,    // Wigglytuff.
+            0,     // Alt form: N/A.
+            4,     // Guaranteed flawless IVs.
+            false, // Not G-max.
+            2,     // HA-locked.
+            0,     // Random gender.
+            0,     // Random shiny.
+            4,     // 4★.
+        );
+        let frame = FrameGenerator::new(raid, 0x775b846f76f1b25d)
+            .next()
+            .and_then(FrameResult::to_option)
+            .unwrap();
+
+        assert_eq!(raid.get_level_range(), (45, 50));
+        assert_eq!(
+            frame.get_stats(&raid, 100),
+            Some(Stats(421, 160, 126, 185, 135, 126))
+        );
+        assert_eq!(
+            frame.get_min_stats(&raid),
+            Some(Stats(194, 74, 59, 85, 63, 59))
+        );
+        assert_eq!(
+            frame.get_max_stats(&raid),
+            Some(Stats(215, 82, 65, 94, 70, 65))
+        );
+    }
+
+    #[test]
+    fn test_filtered_stats() {
+        // Den 100.
+        let mut f = FrameGenerator::new(
+            Raid::new(
+                40,    // Wigglytuff.
+                0,     // Alt form: N/A.
+                4,     // Guaranteed flawless IVs.
+                false, // Not G-max.
+                2,     // HA-locked.
+                0,     // Random gender.
+                0,     // Random shiny.
+                4,     // 4★.
+            ),
+            0x775b846f76f1b25d,
+        );
+
+        let filter = FrameFilter::new().set_stats(
+            100,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SingleStatFilter::new_at_least(126)),
+        );
+
+        f.set_filter(filter);
+
+        assert_eq!(
+            f.take(5)
+                .filter_map(FrameResult::to_option)
+                .map(|frame| frame.nature)
+                .collect::<Vec<Nature>>(),
+            vec![Nature::Adamant, Nature::Calm]
+        );
+    }
+
+    #[test]
+    fn test_frame_ability_names() {
+        // Den 100.
+        let raid = Raid::new(
+            40,    // Wigglytuff.
+            0,     // Alt form: N/A.
+            4,     // Guaranteed flawless IVs.
+            false, // Not G-max.
+            2,     // HA-locked.
+            0,     // Random gender.
+            0,     // Random shiny.
+            4,     // 4★.
+        );
+        let frame = FrameGenerator::new(raid, 0x775b846f76f1b25d)
+            .next()
+            .and_then(FrameResult::to_option)
+            .unwrap();
+
+        assert_eq!(frame.get_ability_id(&raid), Some(119));
+        assert_eq!(frame.get_ability_name(&raid), Some(String::from("Frisk")));
+        assert!(!frame.is_ability_shared(&raid));
+    }
+
+    #[test]
+    fn test_filtered_ability_id() {
+        // Den 14, Shield.
+        let mut f = FrameGenerator::new(
+            Raid::new(
+                439,   // Mime Jr.
+                0,     // Alt form: N/A.
+                1,     // Guaranteed flawless IVs.
+                false, // Not G-max.
+                3,     // Random ability, no HA.
+                0,     // Random gender.
+                0,     // Random shiny.
+                1,     // 1★.
+            ),
+            0x4ab973e61fba4358,
+        );
+
+        // Second ability.
+        f.set_filter(FrameFilter::new().set_ability_id(111));
+
+        assert_eq!(
+            f.take(10)
+                .filter_map(FrameResult::to_option)
+                .map(|frame| frame.seed)
+                .collect::<Vec<u64>>(),
+            vec![
+                0x4ab973e61fba4358,
+                0x4ffed6d064f5180e,
+                0x5fceff8f34a59630,
+                0xe271b1045743008b
+            ]
+        );
+    }
+
     #[test]
     fn test_search() {
         // Excadrill.
@@ -663,6 +998,8 @@ mod test {
                 false, // Not G-max.
                 4,     // Random ability, HA possible.
                 0,     // Random gender.
+                0,     // Random shiny.
+                4,     // 4★.
             ),
             0xc816c270fd1cd8fd,
         );
@@ -711,6 +1048,8 @@ mod test {
                 false, // Not G-max.
                 4,     // Random ability, HA possible.
                 0,     // Random gender.
+                0,     // Random shiny.
+                4,     // 4★.
             ),
             0xc816c270fd1cd8fd,
         );
@@ -0,0 +1,142 @@
+//! Dynamax Adventure (Max Lair) legendary generation.
+//!
+//! Unlike dens, the boss's shininess isn't decided by its seed. The game first rolls for
+//! shininess with a fixed chance, then generates the boss from its seed with the PID forced
+//! shiny or non-shiny to match. The rest of the generation follows the raid routine, with four
+//! guaranteed flawless IVs and no hidden ability.
+use super::super::personal_data::get_personal_info;
+use super::frame::{Frame, FrameGenerator, FrameResult};
+use super::mon::{IVs, Nature, Stats};
+use super::raid::{Raid, Species};
+use wasm_bindgen::prelude::*;
+
+/// Max Lair bosses always have this many flawless IVs.
+pub const MAX_LAIR_FLAWLESS_IVS: u8 = 4;
+
+/// Max Lair bosses are always caught at this level.
+pub const MAX_LAIR_LEVEL: u32 = 70;
+
+/// Returns the boss's shiny chance, as 1 in the returned number.
+/// The shiny charm raises the chance from 1/300 to 1/100.
+#[wasm_bindgen]
+pub fn get_max_lair_shiny_rate(has_shiny_charm: bool) -> u32 {
+    if has_shiny_charm {
+        100
+    } else {
+        300
+    }
+}
+
+/// Returns the chance of seeing at least one shiny boss over a number of adventures.
+#[wasm_bindgen]
+pub fn get_max_lair_shiny_odds(has_shiny_charm: bool, adventures: u32) -> f64 {
+    let miss = 1.0 - 1.0 / get_max_lair_shiny_rate(has_shiny_charm) as f64;
+    1.0 - miss.powi(adventures as i32)
+}
+
+/// A legendary boss at the end of a Max Lair run.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct MaxLairBoss {
+    species: Species,
+    alt_form: u8,
+}
+
+#[wasm_bindgen]
+impl MaxLairBoss {
+    /// Creates a boss, or returns nothing if the species and form have no personal data.
+    pub fn new(species: Species, alt_form: u8) -> Option<MaxLairBoss> {
+        get_personal_info(species as usize, alt_form as usize)?;
+        Some(MaxLairBoss { species, alt_form })
+    }
+
+    pub fn get_species(&self) -> Species {
+        self.species
+    }
+
+    pub fn get_alt_form(&self) -> u8 {
+        self.alt_form
+    }
+
+    /// Returns the raid the boss is generated as, given the outcome of the shiny roll.
+    /// The shiny roll's outcome locks the shininess of the generated PID.
+    pub fn get_raid(&self, is_shiny: bool) -> Raid {
+        let shiny_pool = if is_shiny { 2 } else { 1 };
+        Raid::new(
+            self.species,
+            self.alt_form,
+            MAX_LAIR_FLAWLESS_IVS,
+            false, // Not G-max.
+            3,     // Random ability, no HA.
+            0,     // Random gender.
+            shiny_pool,
+            5, // 5★.
+        )
+    }
+
+    /// Predicts the boss generated from a seed, given the outcome of the shiny roll.
+    pub fn generate(&self, seed: u64, is_shiny: bool) -> Frame {
+        match FrameGenerator::new(self.get_raid(is_shiny), seed).next() {
+            Some(FrameResult::Pass(frame)) => frame,
+            // Generators without a filter pass every frame.
+            _ => unreachable!(),
+        }
+    }
+
+    /// Calculates the stats of a generated boss at the level it is caught at.
+    pub fn get_stats(&self, frame: &Frame) -> Option<Stats> {
+        frame.get_stats(&self.get_raid(false), MAX_LAIR_LEVEL)
+    }
+
+    /// Returns true if a caught boss's IVs and nature match the ones generated from the seed.
+    pub fn verify(&self, seed: u64, ivs: IVs, nature: Nature) -> bool {
+        // Shininess only affects the PID, so either outcome gives the same IVs and nature.
+        let frame = self.generate(seed, false);
+        frame.ivs == ivs && frame.nature == nature
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::mon::{Gender, Shininess};
+    use super::*;
+
+    #[test]
+    fn test_shiny_rate() {
+        assert_eq!(get_max_lair_shiny_rate(false), 300);
+        assert_eq!(get_max_lair_shiny_rate(true), 100);
+        assert_eq!(get_max_lair_shiny_odds(true, 0), 0.0);
+        assert!((get_max_lair_shiny_odds(true, 1) - 0.01).abs() < 1e-9);
+        assert!(get_max_lair_shiny_odds(true, 100) > get_max_lair_shiny_odds(false, 100));
+    }
+
+    #[test]
+    fn test_generate() {
+        let boss = MaxLairBoss::new(382, 0).unwrap(); // Kyogre.
+        assert_eq!(MaxLairBoss::new(9999, 0), None);
+
+        // The shiny roll's outcome decides shininess, whatever the seed.
+        for &seed in [0xbb810e6006a2a035, 0x775b846f76f1b25d, 0].iter() {
+            let frame = boss.generate(seed, false);
+            assert_eq!(frame.shiny, Shininess::None);
+            assert_eq!(frame.gender, Gender::Genderless);
+            assert_ne!(boss.generate(seed, true).shiny, Shininess::None);
+            assert_eq!(boss.generate(seed, true).ivs, frame.ivs);
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let boss = MaxLairBoss::new(144, 1).unwrap(); // Galarian Articuno.
+        let frame = boss.generate(0x775b846f76f1b25d, true);
+        assert_ne!(frame.shiny, Shininess::None);
+        assert!(boss.verify(0x775b846f76f1b25d, frame.ivs, frame.nature));
+        assert!(!boss.verify(0xbb810e6006a2a035, frame.ivs, frame.nature));
+
+        let base = get_personal_info(144, 1).unwrap().get_base_stats();
+        assert_eq!(
+            boss.get_stats(&frame),
+            Some(Stats::calculate(144, &base, &frame.ivs, frame.nature, 70))
+        );
+    }
+}
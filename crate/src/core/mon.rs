@@ -19,6 +19,65 @@ impl fmt::Display for IVs {
     }
 }
 
+/// Computed stats for a mon, in the same order as IVs: HP, Atk, Def, SpA, SpD, Spe.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Stats(pub u32, pub u32, pub u32, pub u32, pub u32, pub u32);
+
+// Shedinja always has exactly 1 HP.
+const SHEDINJA: u32 = 292;
+
+impl Stats {
+    /// Calculates stats from base stats, IVs, nature and level.
+    /// Raid mons are caught with no EVs, so EVs are not taken into account.
+    pub fn calculate(species: u32, base: &Stats, ivs: &IVs, nature: Nature, level: u32) -> Self {
+        let hp = if species == SHEDINJA {
+            1
+        } else {
+            (2 * base.0 + ivs.0) * level / 100 + level + 10
+        };
+        let other = |base: u32, iv: u32, stat: Stat| {
+            let raw = (2 * base + iv) * level / 100 + 5;
+            match nature.get_stat_modifier(stat) {
+                n if n > 0 => raw * 11 / 10,
+                n if n < 0 => raw * 9 / 10,
+                _ => raw,
+            }
+        };
+
+        Stats(
+            hp,
+            other(base.1, ivs.1, Stat::Atk),
+            other(base.2, ivs.2, Stat::Def),
+            other(base.3, ivs.3, Stat::SpA),
+            other(base.4, ivs.4, Stat::SpD),
+            other(base.5, ivs.5, Stat::Spe),
+        )
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}/{}/{}/{}",
+            self.0, self.1, self.2, self.3, self.4, self.5
+        )
+    }
+}
+
+/// A single stat.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Stat {
+    HP,
+    Atk,
+    Def,
+    SpA,
+    SpD,
+    Spe,
+}
+
 /// Whether a mon is shiny, and if so, what type of shiny.
 #[wasm_bindgen]
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
@@ -72,6 +131,17 @@ pub enum Gender {
     Genderless,
 }
 
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Gender::Male => "M",
+            Gender::Female => "F",
+            Gender::Genderless => "-",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
 /// What nature a mon has.
 /// Nature determines the pace of stat progression.
 #[wasm_bindgen]
@@ -104,6 +174,33 @@ pub enum Nature {
     Quirky = 24,
 }
 
+impl Nature {
+    /// Returns 1 if the nature boosts the stat, -1 if it hinders the stat, and 0 otherwise.
+    /// Natures are laid out in a 5x5 grid of (boosted, hindered) stats, in the order
+    /// Atk, Def, Spe, SpA, SpD. Natures on the diagonal are neutral.
+    pub fn get_stat_modifier(self, stat: Stat) -> i8 {
+        let index = match stat {
+            Stat::HP => return 0,
+            Stat::Atk => 0,
+            Stat::Def => 1,
+            Stat::Spe => 2,
+            Stat::SpA => 3,
+            Stat::SpD => 4,
+        };
+        let nature = self as u32;
+        let (boosted, hindered) = (nature / 5, nature % 5);
+        if boosted == hindered {
+            0
+        } else if boosted == index {
+            1
+        } else if hindered == index {
+            -1
+        } else {
+            0
+        }
+    }
+}
+
 // Toxtricity evolves into Amped Form if it has a certain set of natures,
 // and Low Key Form otherwise.
 static TOXTRICITY_AMPED_NATURES: [Nature; 12] = [
@@ -148,6 +245,37 @@ pub fn get_toxtricity_nature(rng: &mut Rng, is_amped: bool) -> Nature {
     }
 }
 
+/// Which of a species' held item slots a wild mon holds.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum HeldItem {
+    None = 0,
+    Common = 1,
+    Rare = 2,
+}
+
+impl HeldItem {
+    /// Converts a held item roll (0-99): 50% common, 5% rare, 45% nothing.
+    pub fn from_roll(roll: u32) -> Self {
+        match roll {
+            0..=49 => HeldItem::Common,
+            50..=54 => HeldItem::Rare,
+            _ => HeldItem::None,
+        }
+    }
+}
+
+impl fmt::Display for HeldItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HeldItem::None => "--",
+            HeldItem::Common => "Common",
+            HeldItem::Rare => "Rare",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// Possible overworld marks.
 #[wasm_bindgen]
 #[derive(PartialEq, Eq, Debug, Copy, Clone, FromPrimitive, ToPrimitive)]
@@ -182,28 +310,115 @@ pub enum PersonalityMark {
     Slump = 27,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+/// Overworld weather. Each kind of weather other than clear skies awards its own mark.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Weather {
+    Clear = 0,
+    Overcast = 1,
+    Rain = 2,
+    Thunderstorm = 3,
+    Snow = 4,
+    Snowstorm = 5,
+    HarshSunlight = 6,
+    Sandstorm = 7,
+    HeavyFog = 8,
+}
+
+/// Time of day. Each awards its own mark.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TimeOfDay {
+    Morning = 0,
+    Day = 1,
+    Evening = 2,
+    Night = 3,
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub enum Mark {
     Rare,
     Personality(PersonalityMark),
     Uncommon,
-    Weather,
-    Time,
+    Weather(Weather),
+    Time(TimeOfDay),
     Fishing,
 }
 
 impl fmt::Display for Mark {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Mark::Personality(m) => write!(f, "{:?}", m),
-            m => write!(f, "{:?}", m),
+            Mark::Rare => write!(f, "Rare"),
+            Mark::Uncommon => write!(f, "Uncommon"),
+            Mark::Fishing => write!(f, "Fishing"),
+            // In-game names, without the trailing "Mark".
+            Mark::Personality(m) => write!(
+                f,
+                "{}",
+                match m {
+                    PersonalityMark::Rowdy => "Rowdy",
+                    PersonalityMark::AbsentMinded => "Absent-Minded",
+                    PersonalityMark::Jittery => "Jittery",
+                    PersonalityMark::Excited => "Excited",
+                    PersonalityMark::Charismatic => "Charismatic",
+                    PersonalityMark::Calmness => "Calmness",
+                    PersonalityMark::Intense => "Intense",
+                    PersonalityMark::ZonedOut => "Zoned-Out",
+                    PersonalityMark::Joyful => "Joyful",
+                    PersonalityMark::Angry => "Angry",
+                    PersonalityMark::Smiley => "Smiley",
+                    PersonalityMark::Teary => "Teary",
+                    PersonalityMark::Upbeat => "Upbeat",
+                    PersonalityMark::Peeved => "Peeved",
+                    PersonalityMark::Intellectual => "Intellectual",
+                    PersonalityMark::Ferocious => "Ferocious",
+                    PersonalityMark::Crafty => "Crafty",
+                    PersonalityMark::Scowling => "Scowling",
+                    PersonalityMark::Kindly => "Kindly",
+                    PersonalityMark::Flustered => "Flustered",
+                    PersonalityMark::PumpedUp => "Pumped-Up",
+                    PersonalityMark::ZeroEnergy => "Zero Energy",
+                    PersonalityMark::Prideful => "Prideful",
+                    PersonalityMark::Unsure => "Unsure",
+                    PersonalityMark::Humble => "Humble",
+                    PersonalityMark::Thorny => "Thorny",
+                    PersonalityMark::Vigor => "Vigor",
+                    PersonalityMark::Slump => "Slump",
+                }
+            ),
+            Mark::Weather(w) => write!(
+                f,
+                "{}",
+                match w {
+                    // Clear skies never award a mark.
+                    Weather::Clear => "--",
+                    Weather::Overcast => "Cloudy",
+                    Weather::Rain => "Rainy",
+                    Weather::Thunderstorm => "Stormy",
+                    Weather::Snow => "Snowy",
+                    Weather::Snowstorm => "Blizzard",
+                    Weather::HarshSunlight => "Dry",
+                    Weather::Sandstorm => "Sandstorm",
+                    Weather::HeavyFog => "Misty",
+                }
+            ),
+            Mark::Time(t) => write!(
+                f,
+                "{}",
+                match t {
+                    TimeOfDay::Morning => "Dawn",
+                    TimeOfDay::Day => "Lunchtime",
+                    TimeOfDay::Evening => "Dusk",
+                    TimeOfDay::Night => "Sleepy-Time",
+                }
+            ),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::Nature;
+    use super::{HeldItem, IVs, Mark, Nature, PersonalityMark, Stat, Stats, TimeOfDay, Weather};
     use num_traits::{FromPrimitive, ToPrimitive};
 
     /// Test conversion between natures.
@@ -215,4 +430,76 @@ mod test {
         // Fails for unrecognized primitives.
         assert_eq!(Nature::from_u32(25), None);
     }
+
+    #[test]
+    fn test_stat_modifiers() {
+        assert_eq!(Nature::Timid.get_stat_modifier(Stat::Spe), 1);
+        assert_eq!(Nature::Timid.get_stat_modifier(Stat::Atk), -1);
+        assert_eq!(Nature::Timid.get_stat_modifier(Stat::HP), 0);
+        assert_eq!(Nature::Adamant.get_stat_modifier(Stat::SpA), -1);
+        assert_eq!(Nature::Calm.get_stat_modifier(Stat::SpD), 1);
+        assert_eq!(Nature::Serious.get_stat_modifier(Stat::Spe), 0);
+    }
+
+    #[test]
+    fn test_stats() {
+        // Garchomp.
+        let base = Stats(108, 130, 95, 80, 85, 102);
+        assert_eq!(
+            Stats::calculate(445, &base, &IVs(31, 31, 31, 31, 31, 31), Nature::Jolly, 100),
+            Stats(357, 296, 226, 176, 206, 264)
+        );
+        assert_eq!(
+            Stats::calculate(445, &base, &IVs(31, 31, 31, 31, 31, 0), Nature::Brave, 50),
+            Stats(183, 165, 115, 100, 105, 96)
+        );
+
+        // Shedinja.
+        let base = Stats(1, 90, 45, 30, 30, 40);
+        assert_eq!(
+            Stats::calculate(292, &base, &IVs(31, 31, 31, 31, 31, 31), Nature::Hardy, 100).0,
+            1
+        );
+    }
+
+    #[test]
+    fn test_held_item_roll() {
+        assert_eq!(HeldItem::from_roll(0), HeldItem::Common);
+        assert_eq!(HeldItem::from_roll(49), HeldItem::Common);
+        assert_eq!(HeldItem::from_roll(50), HeldItem::Rare);
+        assert_eq!(HeldItem::from_roll(54), HeldItem::Rare);
+        assert_eq!(HeldItem::from_roll(55), HeldItem::None);
+        assert_eq!(HeldItem::from_roll(99), HeldItem::None);
+    }
+
+    #[test]
+    fn test_mark_names() {
+        assert_eq!(Mark::Rare.to_string(), "Rare");
+        assert_eq!(Mark::Uncommon.to_string(), "Uncommon");
+        assert_eq!(Mark::Fishing.to_string(), "Fishing");
+        assert_eq!(
+            Mark::Personality(PersonalityMark::AbsentMinded).to_string(),
+            "Absent-Minded"
+        );
+        assert_eq!(
+            Mark::Personality(PersonalityMark::ZonedOut).to_string(),
+            "Zoned-Out"
+        );
+        assert_eq!(
+            Mark::Personality(PersonalityMark::PumpedUp).to_string(),
+            "Pumped-Up"
+        );
+        assert_eq!(
+            Mark::Personality(PersonalityMark::ZeroEnergy).to_string(),
+            "Zero Energy"
+        );
+        assert_eq!(
+            Mark::Personality(PersonalityMark::Rowdy).to_string(),
+            "Rowdy"
+        );
+        assert_eq!(Mark::Weather(Weather::HarshSunlight).to_string(), "Dry");
+        assert_eq!(Mark::Weather(Weather::HeavyFog).to_string(), "Misty");
+        assert_eq!(Mark::Time(TimeOfDay::Night).to_string(), "Sleepy-Time");
+        assert_eq!(Mark::Time(TimeOfDay::Day).to_string(), "Lunchtime");
+    }
 }
@@ -1,41 +1,143 @@
 ///! Generates data for overworld spawns.
 ///! Thanks to Lincoln-LM for the original approach.
 ///! https://github.com/Lincoln-LM/PyNXReader/blob/master/rng/G8RNG.py#L547
+use super::super::personal_data::get_personal_info;
+use super::super::static_data::get_static_encounter;
+use super::advance::{AdvanceModel, MenuPlan};
+use super::filter::{Filter, SpawnFilter};
 use super::frame::FrameGenerator;
-use super::mon::{Ability, IVs, Mark, Nature, PersonalityMark};
+use super::mon::{
+    Ability, Gender, HeldItem, IVs, Mark, Nature, PersonalityMark, Shininess, TimeOfDay, Weather,
+};
 use super::rng::Rng;
+use super::slot::{SlotKind, SlotTable, SlotTables};
+use super::timer::AdvanceTimer;
+use super::tracker::TrackedAdvance;
+use super::trainer::Trainer;
 use num_traits::FromPrimitive;
 use std::fmt;
+use std::iter::FromIterator;
 use wasm_bindgen::prelude::*;
 
 /// Information about the player.
-#[derive(PartialEq, Eq, Debug)]
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub struct Player {
-    tid: u16,
-    sid: u16,
+    trainer: Trainer,
     has_shiny_charm: bool,
     has_mark_charm: bool,
 }
 
+#[wasm_bindgen]
+impl Player {
+    #[wasm_bindgen(constructor)]
+    pub fn new(trainer: &Trainer, has_shiny_charm: bool, has_mark_charm: bool) -> Self {
+        Player {
+            trainer: *trainer,
+            has_shiny_charm,
+            has_mark_charm,
+        }
+    }
+}
+
 /// Min and max level range, inclusive.
+#[wasm_bindgen]
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub struct Level {
     min: u32,
     max: u32,
 }
 
+#[wasm_bindgen]
+impl Level {
+    #[wasm_bindgen(constructor)]
+    pub fn new(min: u32, max: u32) -> Self {
+        assert!(min <= max, "Invalid level range");
+        Level { min, max }
+    }
+}
+
+/// A fixed encounter, such as a legendary, gift or fossil.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct StaticEncounter {
+    name: &'static str,
+    species: u32,
+    alt_form: u8,
+    level: u32,
+    /// Number of guaranteed flawless IVs.
+    flawless_ivs: u8,
+    /// Whether the encounter can never be shiny.
+    shiny_locked: bool,
+    /// Fixed ability, if the encounter doesn't roll for one.
+    ability: Option<Ability>,
+    /// Whether the encounter rolls for a mark. Gifts never have one.
+    can_have_mark: bool,
+}
+
+impl StaticEncounter {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &'static str,
+        species: u32,
+        alt_form: u8,
+        level: u32,
+        flawless_ivs: u8,
+        shiny_locked: bool,
+        ability: Option<Ability>,
+        can_have_mark: bool,
+    ) -> Self {
+        StaticEncounter {
+            name,
+            species,
+            alt_form,
+            level,
+            flawless_ivs,
+            shiny_locked,
+            ability,
+            can_have_mark,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn get_species(&self) -> u32 {
+        self.species
+    }
+
+    pub fn get_alt_form(&self) -> u8 {
+        self.alt_form
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn is_shiny_locked(&self) -> bool {
+        self.shiny_locked
+    }
+}
+
 /// Method of encounter.
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
-#[allow(dead_code)]
 pub enum EncounterMethod {
     Static,
+    /// A catalogued legendary, gift or fossil encounter.
+    Fixed(StaticEncounter),
     Fishing {
         level: Level,
         /// Whether the first and second held item slots are different.
         diff_held_item: bool,
     },
-    /// Overworld or grass encounters.
-    Overworld {
+    /// Symbol encounters, visible in the overworld.
+    Symbol {
+        level: Level,
+        /// Whether the first and second held item slots are different.
+        diff_held_item: bool,
+    },
+    /// Hidden encounters, from rustling grass.
+    Hidden {
         level: Level,
         /// Whether the first and second held item slots are different.
         diff_held_item: bool,
@@ -49,9 +151,28 @@ impl EncounterMethod {
             _ => false,
         }
     }
+
+    /// Returns the kind of encounter table the method picks from, if any.
+    pub fn get_slot_kind(&self) -> Option<SlotKind> {
+        match self {
+            Self::Fishing { .. } => Some(SlotKind::Fishing),
+            Self::Symbol { .. } => Some(SlotKind::Symbol),
+            Self::Hidden { .. } => Some(SlotKind::Hidden),
+            _ => None,
+        }
+    }
+
+    /// Returns the catalogued encounter, if any.
+    pub fn get_fixed(&self) -> Option<&StaticEncounter> {
+        match self {
+            Self::Fixed(encounter) => Some(encounter),
+            _ => None,
+        }
+    }
 }
 
 /// A spawn at a particular advance.
+#[wasm_bindgen(inspectable)]
 #[derive(PartialEq, Eq, Debug)]
 pub struct Spawn {
     advance: u64,
@@ -81,15 +202,18 @@ impl fmt::Display for Spawn {
                     SpawnType::Random {
                         slot,
                         level,
+                        brilliant_roll,
                         brilliant,
+                        ..
                     },
                 ..
             } => write!(
                 f,
-                "{slot:3} {level:2} {brilliant:3} │ {dyn}",
+                "{slot:3} {level:2} {brilliant_roll:3}{brilliant:1} │ {dyn}",
                 slot = slot,
                 level = level,
-                brilliant = brilliant,
+                brilliant_roll = brilliant_roll,
+                brilliant = if *brilliant { "*" } else { " " },
                 dyn = self.dynamic,
             ),
         })
@@ -103,14 +227,263 @@ impl fmt::Display for Spawn {
     }
 }
 
+/// Exposes the same data as the `Display` impl.
+#[wasm_bindgen]
+impl Spawn {
+    /// Number of advances from the starting state. JavaScript numbers hold these exactly
+    /// up to 2^53.
+    #[wasm_bindgen(getter)]
+    pub fn advance(&self) -> f64 {
+        self.advance as f64
+    }
+
+    /// First half of the RNG state at the start of the advance, in hex.
+    #[wasm_bindgen(getter)]
+    pub fn seed_0(&self) -> String {
+        format!("{:016x}", self.full_seed.0)
+    }
+
+    /// Second half of the RNG state at the start of the advance, in hex.
+    #[wasm_bindgen(getter)]
+    pub fn seed_1(&self) -> String {
+        format!("{:016x}", self.full_seed.1)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_static(&self) -> bool {
+        self.spawn == SpawnType::Static
+    }
+
+    /// Species picked by the slot roll, if the area's encounter table is known.
+    #[wasm_bindgen(getter)]
+    pub fn species(&self) -> Option<u32> {
+        match self.spawn {
+            SpawnType::Random { species, .. } => species.map(|(species, _)| species),
+            SpawnType::Static => None,
+        }
+    }
+
+    /// Form picked by the slot roll, if the area's encounter table is known.
+    #[wasm_bindgen(getter)]
+    pub fn alt_form(&self) -> Option<u8> {
+        match self.spawn {
+            SpawnType::Random { species, .. } => species.map(|(_, alt_form)| alt_form),
+            SpawnType::Static => None,
+        }
+    }
+
+    /// Brilliant aura roll (0-999). Random encounters only.
+    #[wasm_bindgen(getter)]
+    pub fn brilliant_roll(&self) -> Option<u32> {
+        match self.spawn {
+            SpawnType::Random { brilliant_roll, .. } => Some(brilliant_roll),
+            SpawnType::Static => None,
+        }
+    }
+
+    /// Whether the spawn has a brilliant aura. Random encounters only.
+    #[wasm_bindgen(getter)]
+    pub fn brilliant(&self) -> Option<bool> {
+        match self.spawn {
+            SpawnType::Random { brilliant, .. } => Some(brilliant),
+            SpawnType::Static => None,
+        }
+    }
+
+    /// Encounter slot roll. Random encounters only.
+    #[wasm_bindgen(getter)]
+    pub fn slot(&self) -> Option<u32> {
+        match self.spawn {
+            SpawnType::Random { slot, .. } => Some(slot),
+            SpawnType::Static => None,
+        }
+    }
+
+    /// Level. Random encounters only.
+    #[wasm_bindgen(getter)]
+    pub fn level(&self) -> Option<u32> {
+        match self.spawn {
+            SpawnType::Random { level, .. } => Some(level),
+            SpawnType::Static => None,
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn ec(&self) -> u32 {
+        self.dynamic.ec
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn pid(&self) -> u32 {
+        self.dynamic.pid
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn shiny(&self) -> Shininess {
+        self.dynamic.shiny
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn nature(&self) -> Nature {
+        self.dynamic.nature
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn ability(&self) -> Ability {
+        self.dynamic.ability
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn ivs(&self) -> IVs {
+        self.dynamic.ivs
+    }
+
+    /// Gender, if the species is known.
+    #[wasm_bindgen(getter)]
+    pub fn gender(&self) -> Option<Gender> {
+        self.dynamic.gender
+    }
+
+    /// Height scalar (0-255).
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> u32 {
+        self.dynamic.height
+    }
+
+    /// Weight scalar (0-255).
+    #[wasm_bindgen(getter)]
+    pub fn weight(&self) -> u32 {
+        self.dynamic.weight
+    }
+
+    /// Held item slot. Random encounters only.
+    #[wasm_bindgen(getter)]
+    pub fn held_item(&self) -> Option<HeldItem> {
+        self.dynamic.held_item
+    }
+
+    /// Returns the ID of the item held by the spawn, if its species is known from the slot.
+    pub fn get_held_item_id(&self) -> Option<u16> {
+        let item = self.dynamic.held_item?;
+        let species = self.species()?;
+        let alt_form = self.alt_form()?;
+        get_personal_info(species as usize, alt_form as usize)?.get_held_item(item)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn fixed_seed(&self) -> u32 {
+        self.dynamic.fixed_seed
+    }
+
+    /// Name of the mark, if any.
+    #[wasm_bindgen(getter)]
+    pub fn mark(&self) -> Option<String> {
+        self.mark.as_ref().map(|m| m.to_string())
+    }
+}
+
+impl Filter<Spawn> for SpawnFilter {
+    fn test(&self, value: &Spawn) -> bool {
+        // Slot, level, brilliance and species only exist for random encounters.
+        let random = match value.spawn {
+            SpawnType::Random {
+                brilliant,
+                slot,
+                level,
+                ..
+            } => Some((brilliant, slot, level)),
+            SpawnType::Static => None,
+        };
+
+        if let Some(species) = self.species {
+            if value.species() != Some(species) {
+                return false;
+            }
+        }
+        if let Some(f) = self.shiny {
+            if !f.test(&value.dynamic.shiny) {
+                return false;
+            }
+        }
+        if let Some(f) = self.mark {
+            if !f.test(&value.mark) {
+                return false;
+            }
+        }
+        if let Some(mark) = self.personality_mark {
+            if value.mark != Some(Mark::Personality(mark)) {
+                return false;
+            }
+        }
+        if let Some(f) = self.nature {
+            if !f.test(&value.dynamic.nature) {
+                return false;
+            }
+        }
+        if let Some(f) = self.ivs {
+            if !f.test(&value.dynamic.ivs) {
+                return false;
+            }
+        }
+        if let Some(f) = self.slot {
+            match random {
+                Some((_, slot, _)) if f.test(&slot) => {}
+                _ => return false,
+            }
+        }
+        if let Some(f) = self.level {
+            match random {
+                Some((_, _, level)) if f.test(&level) => {}
+                _ => return false,
+            }
+        }
+        if let Some(f) = self.brilliant {
+            match random {
+                Some((brilliant, _, _)) if brilliant == f => {}
+                _ => return false,
+            }
+        }
+        if let Some(f) = self.ability {
+            if !f.test(&value.dynamic.ability) {
+                return false;
+            }
+        }
+        if let Some(f) = self.gender {
+            match value.dynamic.gender {
+                Some(gender) if f.test(&gender) => {}
+                _ => return false,
+            }
+        }
+        if let Some(f) = self.height {
+            if !f.test(&value.dynamic.height) {
+                return false;
+            }
+        }
+        if let Some(f) = self.weight {
+            if !f.test(&value.dynamic.weight) {
+                return false;
+            }
+        }
+        if let Some(item) = self.held_item {
+            if value.dynamic.held_item != Some(item) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 enum SpawnType {
     Static,
     Random {
-        /// Brilliant aura.
-        brilliant: u32,
+        /// Brilliant aura roll, and whether it gave a brilliant aura.
+        brilliant_roll: u32,
+        brilliant: bool,
         /// Encounter slot.
         slot: u32,
+        /// Species and form in the slot, if the area's encounter table is known.
+        species: Option<(u32, u8)>,
         level: u32,
     },
 }
@@ -119,9 +492,18 @@ enum SpawnType {
 struct DynamicStats {
     ec: u32,
     pid: u32,
+    /// Shininess of the final PID for the player.
+    shiny: Shininess,
     nature: Nature,
     ability: Ability,
+    /// Gender, if the species is known.
+    gender: Option<Gender>,
     ivs: IVs,
+    /// Height and weight scalars, from 0 to 255.
+    height: u32,
+    weight: u32,
+    /// Held item slot. Static encounters have fixed items, so don't roll for one.
+    held_item: Option<HeldItem>,
     fixed_seed: u32,
 }
 
@@ -129,24 +511,118 @@ impl fmt::Display for DynamicStats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{pid:8x} │ {nature:<7}  {ability:2} {ivs:<17} │ ",
+            "{pid:8x} {shiny:1} │ {nature:<7}  {ability:2} {gender:1} {ivs:<17} {height:3} {weight:3} {item:6} │ ",
             pid = self.pid,
+            shiny = format!("{}", self.shiny),
             // Need to do all this eta-expansion to get widths and alignment to work correctly.
             nature = format!("{:?}", self.nature),
             ability = format!("{}", self.ability),
+            gender = match self.gender {
+                Some(gender) => format!("{}", gender),
+                None => "?".to_string(),
+            },
             ivs = format!("{}", self.ivs),
+            height = self.height,
+            weight = self.weight,
+            item = match self.held_item {
+                Some(item) => format!("{}", item),
+                None => "--".to_string(),
+            },
         )
     }
 }
 
+/// Bonuses from battling a species, by the number battled.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct KoBonus {
+    /// Fewest battles needed for the bonus.
+    min_ko_count: u32,
+    /// Extra shiny rolls.
+    shiny_rolls: u32,
+    /// Brilliant aura chance, out of 1000.
+    brilliant_rate: u32,
+    /// Guaranteed flawless IVs for brilliant spawns.
+    brilliant_flawless_ivs: u8,
+}
+
+/// Bonuses by number battled, in increasing order.
+static KO_BONUSES: [KoBonus; 8] = [
+    KoBonus {
+        min_ko_count: 0,
+        shiny_rolls: 0,
+        brilliant_rate: 0,
+        brilliant_flawless_ivs: 0,
+    },
+    KoBonus {
+        min_ko_count: 1,
+        shiny_rolls: 0,
+        brilliant_rate: 10,
+        brilliant_flawless_ivs: 2,
+    },
+    KoBonus {
+        min_ko_count: 20,
+        shiny_rolls: 0,
+        brilliant_rate: 20,
+        brilliant_flawless_ivs: 2,
+    },
+    KoBonus {
+        min_ko_count: 50,
+        shiny_rolls: 1,
+        brilliant_rate: 25,
+        brilliant_flawless_ivs: 3,
+    },
+    KoBonus {
+        min_ko_count: 100,
+        shiny_rolls: 2,
+        brilliant_rate: 30,
+        brilliant_flawless_ivs: 3,
+    },
+    KoBonus {
+        min_ko_count: 200,
+        shiny_rolls: 3,
+        brilliant_rate: 30,
+        brilliant_flawless_ivs: 3,
+    },
+    KoBonus {
+        min_ko_count: 300,
+        shiny_rolls: 4,
+        brilliant_rate: 30,
+        brilliant_flawless_ivs: 4,
+    },
+    KoBonus {
+        min_ko_count: 500,
+        shiny_rolls: 5,
+        brilliant_rate: 30,
+        brilliant_flawless_ivs: 4,
+    },
+];
+
+/// Returns the bonuses for the given number battled.
+fn get_ko_bonus(ko_count: u32) -> KoBonus {
+    *KO_BONUSES
+        .iter()
+        .rev()
+        .find(|bonus| bonus.min_ko_count <= ko_count)
+        .unwrap()
+}
+
 /// Overworld state machine, taking an initial seed and generating frames.
 #[wasm_bindgen(inspectable)]
 #[derive(PartialEq, Eq, Debug)]
 pub struct OverworldState {
     player: Player,
-    /// Whether there is active weather in the area.
-    has_weather: bool,
+    /// Current weather in the area.
+    weather: Weather,
+    time: TimeOfDay,
     encounter: EncounterMethod,
+    /// Species and form being generated, if known.
+    species: Option<(u32, u8)>,
+    /// Number of the species battled, as recorded in the Pokédex.
+    ko_count: u32,
+    /// Encounter table of the current area, if known.
+    slots: Option<SlotTable>,
+    /// How many advances each tick and menu close take.
+    model: AdvanceModel,
     rng: Rng,
     /// Starting seed of the current frame.
     seed: (u64, u64),
@@ -154,6 +630,227 @@ pub struct OverworldState {
     advances: u64,
 }
 
+/// Parses both halves of the RNG state from hex.
+pub(super) fn parse_seed_pair(seed_0: &str, seed_1: &str) -> Result<(u64, u64), JsValue> {
+    Ok((
+        parse_seed(seed_0).map_err(JsValue::from)?,
+        parse_seed(seed_1).map_err(JsValue::from)?,
+    ))
+}
+
+/// Parses a 64-bit half of the RNG state from hex, with or without a leading "0x".
+fn parse_seed(seed: &str) -> Result<u64, String> {
+    let seed = seed.trim();
+    let digits = seed
+        .strip_prefix("0x")
+        .or_else(|| seed.strip_prefix("0X"))
+        .unwrap_or(seed);
+    u64::from_str_radix(digits, 16).map_err(|_| format!("Invalid seed: {}", seed))
+}
+
+#[wasm_bindgen]
+impl OverworldState {
+    /// Creates a state for a static encounter, from the two halves of the RNG state in hex.
+    pub fn new_static(
+        player: &Player,
+        weather: Weather,
+        time: TimeOfDay,
+        seed_0: &str,
+        seed_1: &str,
+    ) -> Result<OverworldState, JsValue> {
+        let seed = parse_seed_pair(seed_0, seed_1)?;
+        Ok(OverworldState::new(
+            *player,
+            weather,
+            time,
+            seed,
+            EncounterMethod::Static,
+        ))
+    }
+
+    /// Creates a state for a fishing encounter, from the two halves of the RNG state in hex.
+    pub fn new_fishing(
+        player: &Player,
+        weather: Weather,
+        time: TimeOfDay,
+        seed_0: &str,
+        seed_1: &str,
+        level: &Level,
+        diff_held_item: bool,
+    ) -> Result<OverworldState, JsValue> {
+        let seed = parse_seed_pair(seed_0, seed_1)?;
+        Ok(OverworldState::new(
+            *player,
+            weather,
+            time,
+            seed,
+            EncounterMethod::Fishing {
+                level: *level,
+                diff_held_item,
+            },
+        ))
+    }
+
+    /// Creates a state for a symbol encounter visible in the overworld, from the two halves of
+    /// the RNG state in hex.
+    pub fn new_overworld(
+        player: &Player,
+        weather: Weather,
+        time: TimeOfDay,
+        seed_0: &str,
+        seed_1: &str,
+        level: &Level,
+        diff_held_item: bool,
+    ) -> Result<OverworldState, JsValue> {
+        let seed = parse_seed_pair(seed_0, seed_1)?;
+        Ok(OverworldState::new(
+            *player,
+            weather,
+            time,
+            seed,
+            EncounterMethod::Symbol {
+                level: *level,
+                diff_held_item,
+            },
+        ))
+    }
+
+    /// Creates a state for a hidden encounter in rustling grass, from the two halves of the
+    /// RNG state in hex.
+    pub fn new_hidden(
+        player: &Player,
+        weather: Weather,
+        time: TimeOfDay,
+        seed_0: &str,
+        seed_1: &str,
+        level: &Level,
+        diff_held_item: bool,
+    ) -> Result<OverworldState, JsValue> {
+        let seed = parse_seed_pair(seed_0, seed_1)?;
+        Ok(OverworldState::new(
+            *player,
+            weather,
+            time,
+            seed,
+            EncounterMethod::Hidden {
+                level: *level,
+                diff_held_item,
+            },
+        ))
+    }
+
+    /// Creates a state for a catalogued legendary, gift or fossil encounter, from the two
+    /// halves of the RNG state in hex.
+    pub fn new_static_encounter(
+        player: &Player,
+        weather: Weather,
+        time: TimeOfDay,
+        seed_0: &str,
+        seed_1: &str,
+        name: &str,
+    ) -> Result<OverworldState, JsValue> {
+        let seed = parse_seed_pair(seed_0, seed_1)?;
+        let encounter = get_static_encounter(name)
+            .ok_or_else(|| JsValue::from(format!("Unknown encounter: {}", name)))?;
+        let mut state = OverworldState::new(
+            *player,
+            weather,
+            time,
+            seed,
+            EncounterMethod::Fixed(encounter),
+        );
+        state.set_species(encounter.species, encounter.alt_form);
+        Ok(state)
+    }
+
+    /// Sets the species being generated, so that its fixed gender and held items can be looked up.
+    pub fn set_species(&mut self, species: u32, alt_form: u8) {
+        self.species = Some((species, alt_form));
+    }
+
+    /// Sets the area being encountered in, so that each spawn's slot is mapped to a species and
+    /// level range. The area's table is picked for the encounter method and current weather.
+    pub fn set_area(&mut self, tables: &SlotTables, area: &str) -> Result<(), JsValue> {
+        let table = self
+            .encounter
+            .get_slot_kind()
+            .and_then(|kind| tables.find(area, kind, self.weather))
+            .ok_or_else(|| JsValue::from(format!("No encounter table for {}", area)))?;
+        self.set_slot_table(table.clone());
+        Ok(())
+    }
+
+    /// Sets the number of the species battled, which affects shiny and brilliant aura odds.
+    pub fn set_ko_count(&mut self, ko_count: u32) {
+        self.ko_count = ko_count;
+    }
+
+    /// Moves the state to an advance found by an `AdvanceTracker`. The tracker should have been
+    /// created from the same state as this one, so that advances are counted from the same start.
+    pub fn jump_to(&mut self, tracked: &TrackedAdvance) {
+        let (s0, s1) = tracked.get_state();
+        self.rng.reset_state(s0, s1);
+        self.seed = (s0, s1);
+        self.advances = tracked.get_advance();
+    }
+
+    /// Sets the NPCs on the map, so that each tick takes an advance for each of them.
+    pub fn set_advance_model(&mut self, model: AdvanceModel) {
+        self.model = model;
+    }
+
+    /// Closes the menu, moving the state by however many advances it takes.
+    pub fn close_menu(&mut self) {
+        let taken = self.model.close_menu(&mut self.rng);
+        self.seed = self.rng.get_state();
+        self.advances += taken;
+    }
+
+    /// Plans the menu closes and raw advances that reach the target advance from the current
+    /// one. Returns nothing if the target has already passed, isn't a number of advances, or
+    /// can't be reached.
+    pub fn plan_menu_closes(&self, target: f64) -> Option<MenuPlan> {
+        if !target.is_finite() || target < self.advances as f64 {
+            return None;
+        }
+        let target = target as u64;
+        self.model
+            .plan(self.seed, target.checked_sub(self.advances)?)
+    }
+
+    /// Number of advances made so far.
+    pub fn get_advance(&self) -> f64 {
+        self.advances as f64
+    }
+
+    /// Returns the timer phases, in milliseconds, to wait from the current advance to the target.
+    pub fn get_timer_phases(&self, timer: &AdvanceTimer, target: f64) -> Vec<f64> {
+        timer.get_phases(self.get_advance(), target)
+    }
+
+    /// Lists the spawns for the next few advances, moving the state past them.
+    pub fn list_spawns(&mut self, count: usize) -> js_sys::Array {
+        js_sys::Array::from_iter(self.by_ref().take(count).map(JsValue::from))
+    }
+
+    /// Searches the next `max_advances` advances for the first spawn matching the filter.
+    /// The state is left just past the spawn found, or past the whole budget.
+    pub fn search(&mut self, filter: &SpawnFilter, max_advances: usize) -> Option<Spawn> {
+        self.by_ref()
+            .take(max_advances)
+            .find(|spawn| filter.test(spawn))
+    }
+
+    /// Lists every spawn matching the filter within the next `max_advances` advances.
+    pub fn search_all(&mut self, filter: &SpawnFilter, max_advances: usize) -> js_sys::Array {
+        js_sys::Array::from_iter(
+            self.find_all(filter, max_advances)
+                .into_iter()
+                .map(JsValue::from),
+        )
+    }
+}
+
 impl Iterator for OverworldState {
     type Item = Spawn;
 
@@ -165,17 +862,36 @@ impl Iterator for OverworldState {
 }
 
 impl OverworldState {
+    /// Returns every spawn matching the filter within the next `max_advances` advances.
+    pub fn find_all(&mut self, filter: &SpawnFilter, max_advances: usize) -> Vec<Spawn> {
+        self.by_ref()
+            .take(max_advances)
+            .filter(|spawn| filter.test(spawn))
+            .collect()
+    }
+
+    /// Sets the encounter table that spawns' slots are mapped through.
+    pub fn set_slot_table(&mut self, table: SlotTable) {
+        self.slots = Some(table);
+    }
+
     /// Create a new overworld state.
     pub fn new(
         player: Player,
-        has_weather: bool,
+        weather: Weather,
+        time: TimeOfDay,
         seed: (u64, u64),
         encounter: EncounterMethod,
     ) -> Self {
         Self {
             player,
-            has_weather,
+            weather,
+            time,
             encounter,
+            species: None,
+            ko_count: 0,
+            slots: None,
+            model: AdvanceModel::new(0),
             rng: Rng::from_state(seed.0, seed.1),
             seed,
             advances: 0,
@@ -192,9 +908,10 @@ impl OverworldState {
         // For a static spawn, no need to compute the species slot, level, mark, or brilliance.
         let encounter = self.encounter.clone();
         let spawn = match encounter {
-            EncounterMethod::Static => {
+            EncounterMethod::Static | EncounterMethod::Fixed(_) => {
                 self.rng.next_int_max(100);
-                let dynamic = self.get_current_spawn_helper();
+                let min_flawless_ivs = encounter.get_fixed().map_or(0, |e| e.flawless_ivs);
+                let dynamic = self.get_current_spawn_helper(min_flawless_ivs, self.species);
 
                 // If it's a static encounter, this is where we roll the mark.
                 let mark = self.get_mark();
@@ -208,26 +925,44 @@ impl OverworldState {
                 }
             }
             EncounterMethod::Fishing { ref level, .. }
-            | EncounterMethod::Overworld { ref level, .. } => {
-                // Need to make a few throwaway calls to advance the state. Encounter slots?
+            | EncounterMethod::Symbol { ref level, .. }
+            | EncounterMethod::Hidden { ref level, .. } => {
+                // Need to make a few throwaway calls to advance the state. These are the same for
+                // every kind of random encounter until spawns captured in game show otherwise.
                 self.rng.next_int(u32::MAX);
                 self.rng.next_int_max(100);
                 self.rng.next_int_max(100);
 
                 // Generate the species encounter slot and stats.
                 let slot = self.rng.next_int_max(100);
-                let level = self.get_level(level);
+                let entry = self
+                    .slots
+                    .as_ref()
+                    .and_then(|table| table.get_slot(slot))
+                    .copied();
+                let level = self.get_level(entry.as_ref().map_or(level, |e| e.get_level()));
                 let mark = self.get_mark();
-                let brilliant = self.rng.next_int_max(1000);
+                let ko_bonus = get_ko_bonus(self.ko_count);
+                let brilliant_roll = self.rng.next_int_max(1000);
+                let brilliant = brilliant_roll < ko_bonus.brilliant_rate;
+                let min_flawless_ivs = if brilliant {
+                    ko_bonus.brilliant_flawless_ivs
+                } else {
+                    0
+                };
 
-                let dynamic = self.get_current_spawn_helper();
+                // The slot's species takes priority over the one set for the whole state.
+                let species = entry.map(|e| (e.species(), e.alt_form())).or(self.species);
+                let dynamic = self.get_current_spawn_helper(min_flawless_ivs, species);
 
                 Spawn {
                     advance: self.advances,
                     full_seed,
                     spawn: SpawnType::Random {
+                        brilliant_roll,
                         brilliant,
                         slot,
+                        species: entry.map(|e| (e.species(), e.alt_form())),
                         level,
                     },
                     mark,
@@ -239,49 +974,73 @@ impl OverworldState {
         spawn
     }
 
-    /// Reset the RNG to the state at the start of the current advance, then advance once.
+    /// Reset the RNG to the state at the start of the current advance, then advance one tick.
     /// Behaves as if we hadn't spawned the mon during this frame, and just advanced to the next frame.
     fn advance(&mut self) {
         self.rng.reset_state(self.seed.0, self.seed.1);
-        self.rng.next();
+        let per_tick = self.model.get_advances_per_tick();
+        for _ in 0..per_tick {
+            self.rng.next();
+        }
         self.seed = self.rng.get_state();
-        self.advances += 1;
+        self.advances += per_tick;
     }
 
     /// Logic used for calculating the stats of the current advance, common across static and non-static spawns.
-    fn get_current_spawn_helper(&mut self) -> DynamicStats {
+    fn get_current_spawn_helper(
+        &mut self,
+        min_flawless_ivs: u8,
+        species: Option<(u32, u8)>,
+    ) -> DynamicStats {
+        let info = species
+            .and_then(|(species, alt_form)| get_personal_info(species as usize, alt_form as usize));
         // Compute shiny, used for calculating fixed values.
-        let is_shiny = self.get_shiny();
-        self.rng.next_int_max(2); // Love to cargo cult.
+        let fixed = self.encounter.get_fixed().copied();
+        // Shiny locked encounters still roll, but the result is thrown away.
+        // `is_some_and` needs a newer toolchain than the crate supports.
+        #[allow(clippy::unnecessary_map_or)]
+        let is_shiny = self.get_shiny() && !fixed.map_or(false, |e| e.shiny_locked);
+        let gender = self.get_gender(info.map(|info| info.get_gender_ratio()));
 
         let nature = self.get_nature();
-        let ability = self.get_ability();
+        let ability = match fixed.and_then(|e| e.ability) {
+            Some(ability) => ability,
+            None => self.get_ability(),
+        };
 
-        // If we need to roll for the held item, account for that in the RNG state.
-        match self.encounter {
+        // Only roll for the held item if the species' item slots differ.
+        let held_item = match self.encounter {
             EncounterMethod::Fishing { diff_held_item, .. }
-            | EncounterMethod::Overworld { diff_held_item, .. } => {
+            | EncounterMethod::Symbol { diff_held_item, .. }
+            | EncounterMethod::Hidden { diff_held_item, .. } => {
                 if diff_held_item {
-                    self.rng.next_int_max(100);
+                    Some(HeldItem::from_roll(self.rng.next_int_max(100)))
+                } else {
+                    // Without a roll, the common item is held, if the species has one.
+                    info.and_then(|info| info.get_held_item(HeldItem::Common))
+                        .map(|_| HeldItem::Common)
                 }
             }
-            _ => {}
-        }
+            _ => None,
+        };
 
         // Compute the fixed seed.
         let fixed_seed = self.rng.next_int(u32::MAX);
-        let (ec, pid, ivs) = self.calculate_fixed(fixed_seed, is_shiny, 0);
-
-        // TODO(slim): Compute XOR here.
-        // xor < 16 star
-        // xor == 0 square
+        let (ec, pid, ivs, height, weight) =
+            self.calculate_fixed(fixed_seed, is_shiny, min_flawless_ivs);
+        let shiny = self.player.trainer.get_shininess(pid);
 
         DynamicStats {
             ec,
             pid,
+            shiny,
             ivs,
             nature,
             ability,
+            gender,
+            height,
+            weight,
+            held_item,
             fixed_seed,
         }
     }
@@ -297,6 +1056,12 @@ impl OverworldState {
 
     /// Compute the mark for the spawn, if any.
     fn get_mark(&mut self) -> Option<Mark> {
+        if let Some(encounter) = self.encounter.get_fixed() {
+            if !encounter.can_have_mark {
+                return None;
+            }
+        }
+
         // If the user has the Mark Charm, roll three times instead of once.
         let iters = if self.player.has_mark_charm { 3 } else { 1 };
 
@@ -315,10 +1080,10 @@ impl OverworldState {
                 return PersonalityMark::from_u32(mark).map(Mark::Personality);
             } else if uncommon == 0 {
                 return Some(Mark::Uncommon);
-            } else if weather == 0 && self.has_weather {
-                return Some(Mark::Weather);
+            } else if weather == 0 && self.weather != Weather::Clear {
+                return Some(Mark::Weather(self.weather));
             } else if time == 0 {
-                return Some(Mark::Time);
+                return Some(Mark::Time(self.time));
             } else if fishing == 0 && self.encounter.is_fishing() {
                 return Some(Mark::Fishing);
             }
@@ -328,10 +1093,10 @@ impl OverworldState {
     }
 
     fn get_shiny(&mut self) -> bool {
-        // Concatenate tid and sid.
-        let tidsid = ((self.player.sid as u32) << 16) | self.player.tid as u32;
-        // If the user has the Shiny Charm, roll three times instead of once.
-        let iters = if self.player.has_shiny_charm { 3 } else { 1 };
+        let tidsid = self.player.trainer.get_tidsid();
+        // The Shiny Charm adds two rolls, and battling the species enough adds more.
+        let charm_rolls = if self.player.has_shiny_charm { 2 } else { 0 };
+        let iters = 1 + charm_rolls + get_ko_bonus(self.ko_count).shiny_rolls;
 
         for _ in 0..iters {
             let mock_pid = self.rng.next_int(u32::MAX);
@@ -343,6 +1108,19 @@ impl OverworldState {
         false
     }
 
+    /// Compute the gender from the species' gender ratio, or nothing if the species is unknown.
+    fn get_gender(&mut self, gender_ratio: Option<u8>) -> Option<Gender> {
+        // The roll is always made, whatever the species, so it never shifts the later calls.
+        let roll = self.rng.next_int_max(2);
+        match gender_ratio? {
+            255 => Some(Gender::Genderless),
+            254 => Some(Gender::Female),
+            0 => Some(Gender::Male),
+            _ if roll == 1 => Some(Gender::Female),
+            _ => Some(Gender::Male),
+        }
+    }
+
     fn get_nature(&mut self) -> Nature {
         Nature::from_u32(self.rng.next_int_max(25)).unwrap()
     }
@@ -360,50 +1138,79 @@ impl OverworldState {
         fixed_seed: u32,
         is_shiny: bool,
         min_flawless_ivs: u8,
-    ) -> (u32, u32, IVs) {
+    ) -> (u32, u32, IVs, u32, u32) {
         // Why is the fixed_seed only 32 bits?
         let mut rng = Rng::new(fixed_seed as u64);
         let ec = rng.next_int(u32::MAX);
         let mut pid = rng.next_int(u32::MAX);
-        let tidsid = ((self.player.tid as u32) << 16) | self.player.sid as u32;
-        let sv = FrameGenerator::get_shiny_value(pid, tidsid);
+        let trainer = &self.player.trainer;
+        let is_pid_shiny = trainer.is_shiny(pid);
 
         // Fix the PID to reflect the actual shininess.
-        // TODO(slim): Double-check this logic.
         // Encounter not shiny, but the PID is.
-        if !is_shiny && sv < 16 {
+        if !is_shiny && is_pid_shiny {
             // Make PID unshiny.
             pid ^= 0x10000000;
-        } else if is_shiny && sv >= 16 {
+        } else if is_shiny && !is_pid_shiny {
             // Encounter is shiny, but the PID isn't.
             // Force the PID to be square.
             let lsbs = pid & 0xFFFF;
-            let tsv = self.player.tid ^ self.player.sid;
-            pid = ((tsv as u32 ^ lsbs) << 16) | lsbs;
+            pid = ((trainer.get_shiny_xor_base() as u32 ^ lsbs) << 16) | lsbs;
         }
 
         let ivs = FrameGenerator::get_ivs(&mut rng, min_flawless_ivs);
+        let height = rng.next_int_max(0x81) + rng.next_int_max(0x80);
+        let weight = rng.next_int_max(0x81) + rng.next_int_max(0x80);
 
-        (ec, pid, ivs)
+        (ec, pid, ivs, height, weight)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::slot::EncounterSlot;
     use super::*;
+    use crate::core::filter::ShinyFilter;
     use num_traits::FromPrimitive;
 
+    /// Starting state shared by the generator tests.
+    const SEED: (u64, u64) = (0x5e5c928d61792fed, 0xed608999e1410aa9);
+
+    /// The trainer from the fixtures.
+    fn new_trainer() -> Trainer {
+        Trainer::new(57649, 60914)
+    }
+
+    /// Creates a state for the fixture trainer in clear weather during the day.
+    fn new_state(has_charms: bool, encounter: EncounterMethod) -> OverworldState {
+        let player = Player::new(&new_trainer(), has_charms, has_charms);
+        OverworldState::new(player, Weather::Clear, TimeOfDay::Day, SEED, encounter)
+    }
+
+    /// Creates a static encounter state without charms.
+    fn new_static_state() -> OverworldState {
+        new_state(false, EncounterMethod::Static)
+    }
+
+    /// Counts the IVs that are 31.
+    fn count_flawless(ivs: IVs) -> usize {
+        [ivs.0, ivs.1, ivs.2, ivs.3, ivs.4, ivs.5]
+            .iter()
+            .filter(|&&iv| iv == 31)
+            .count()
+    }
+
     #[test]
     /// Helper function for quickly dumping the next few advances.
     fn print_advances() {
         let state = OverworldState::new(
             Player {
-                tid: 57649,
-                sid: 60914,
+                trainer: Trainer::new(57649, 60914),
                 has_shiny_charm: true,
                 has_mark_charm: true,
             },
-            false,
+            Weather::Clear,
+            TimeOfDay::Day,
             (0x3b5fd0311052d728, 0xe740f00aa3044772),
             EncounterMethod::Static,
         );
@@ -417,12 +1224,12 @@ mod tests {
     fn test_static_encounter() {
         let state = OverworldState::new(
             Player {
-                tid: 57649,
-                sid: 60914,
+                trainer: Trainer::new(57649, 60914),
                 has_shiny_charm: true,
                 has_mark_charm: true,
             },
-            true,                                     // has_weather
+            Weather::Overcast,
+            TimeOfDay::Day,
             (0x5e5c928d61792fed, 0xed608999e1410aa9), // seed
             EncounterMethod::Static,
         );
@@ -436,10 +1243,15 @@ mod tests {
                     full_seed: (0x5e5c928d61792fed, 0xed608999e1410aa9),
                     dynamic: DynamicStats {
                         ec: 0xb4421f74,
+                        shiny: Shininess::None,
                         pid: 0xc9d20df8,
                         fixed_seed: 0x91a4b519,
                         nature: Nature::from_u32(13).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 138,
+                        weight: 158,
                         ivs: IVs(22, 5, 25, 8, 5, 11)
                     },
                     mark: Some(Mark::Uncommon)
@@ -450,10 +1262,15 @@ mod tests {
                     full_seed: (0x2549e203482279d6, 0x704a89667836290),
                     dynamic: DynamicStats {
                         ec: 0x24008bd9,
+                        shiny: Shininess::None,
                         pid: 0x6b3179c9,
                         fixed_seed: 0x163217e,
                         nature: Nature::from_u32(21).unwrap(),
                         ability: Ability::First,
+                        held_item: None,
+                        gender: None,
+                        height: 169,
+                        weight: 222,
                         ivs: IVs(24, 4, 28, 17, 23, 8)
                     },
                     mark: Some(Mark::Uncommon)
@@ -464,10 +1281,15 @@ mod tests {
                     full_seed: (0x6b90474de2c252a4, 0xf42368c449a952a5),
                     dynamic: DynamicStats {
                         ec: 0x24008bd9,
+                        shiny: Shininess::None,
                         pid: 0x6b3179c9,
                         fixed_seed: 0x163217e,
                         nature: Nature::from_u32(21).unwrap(),
                         ability: Ability::First,
+                        held_item: None,
+                        gender: None,
+                        height: 169,
+                        weight: 222,
                         ivs: IVs(24, 4, 28, 17, 23, 8)
                     },
                     mark: Some(Mark::Uncommon)
@@ -478,10 +1300,15 @@ mod tests {
                     full_seed: (0xfdd846b00f019046, 0x6d600033f665f135),
                     dynamic: DynamicStats {
                         ec: 0x94d85dd7,
+                        shiny: Shininess::None,
                         pid: 0x9d6c7d1,
                         fixed_seed: 0x723af37c,
                         nature: Nature::from_u32(11).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 73,
+                        weight: 127,
                         ivs: IVs(18, 7, 6, 31, 16, 23)
                     },
                     mark: Some(Mark::Uncommon)
@@ -492,10 +1319,15 @@ mod tests {
                     full_seed: (0x6634be77deeab935, 0x2c8c2e721708d07f),
                     dynamic: DynamicStats {
                         ec: 0x94d85dd7,
+                        shiny: Shininess::None,
                         pid: 0x9d6c7d1,
                         fixed_seed: 0x723af37c,
                         nature: Nature::from_u32(11).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 73,
+                        weight: 127,
                         ivs: IVs(18, 7, 6, 31, 16, 23)
                     },
                     mark: Some(Mark::Uncommon)
@@ -506,10 +1338,15 @@ mod tests {
                     full_seed: (0xad63b35e95ce5df4, 0x3c4d2949571200b9),
                     dynamic: DynamicStats {
                         ec: 0x323b2e8c,
+                        shiny: Shininess::None,
                         pid: 0x6c0dd0f,
                         fixed_seed: 0xf9dc431,
                         nature: Nature::from_u32(15).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 115,
+                        weight: 71,
                         ivs: IVs(10, 0, 27, 20, 31, 0)
                     },
                     mark: Some(Mark::Uncommon)
@@ -520,10 +1357,15 @@ mod tests {
                     full_seed: (0x55ac96966b3c3efe, 0x5b8ba9b225d342f8),
                     dynamic: DynamicStats {
                         ec: 0x323b2e8c,
+                        shiny: Shininess::None,
                         pid: 0x6c0dd0f,
                         fixed_seed: 0xf9dc431,
                         nature: Nature::from_u32(15).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 115,
+                        weight: 71,
                         ivs: IVs(10, 0, 27, 20, 31, 0)
                     },
                     mark: Some(Mark::Uncommon)
@@ -534,10 +1376,15 @@ mod tests {
                     full_seed: (0xa7684df5ccbcd090, 0xddef80c1c4e7e489),
                     dynamic: DynamicStats {
                         ec: 0x323b2e8c,
+                        shiny: Shininess::None,
                         pid: 0x6c0dd0f,
                         fixed_seed: 0xf9dc431,
                         nature: Nature::from_u32(15).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 115,
+                        weight: 71,
                         ivs: IVs(10, 0, 27, 20, 31, 0)
                     },
                     mark: Some(Mark::Uncommon)
@@ -548,10 +1395,15 @@ mod tests {
                     full_seed: (0x427f79bface55c54, 0xb66832f50f9a681),
                     dynamic: DynamicStats {
                         ec: 0xabc3d19d,
+                        shiny: Shininess::None,
                         pid: 0x38f83bce,
                         fixed_seed: 0x89266742,
                         nature: Nature::from_u32(4).unwrap(),
                         ability: Ability::First,
+                        held_item: None,
+                        gender: None,
+                        height: 93,
+                        weight: 135,
                         ivs: IVs(6, 12, 5, 24, 5, 27)
                     },
                     mark: Some(Mark::Uncommon)
@@ -562,10 +1414,15 @@ mod tests {
                     full_seed: (0xc25e3d0528b85ac, 0x839f5aa9233f521f),
                     dynamic: DynamicStats {
                         ec: 0x8a97dbf1,
+                        shiny: Shininess::None,
                         pid: 0x1d004a75,
                         fixed_seed: 0x67fa7196,
                         nature: Nature::from_u32(17).unwrap(),
                         ability: Ability::Second,
+                        held_item: None,
+                        gender: None,
+                        height: 116,
+                        weight: 98,
                         ivs: IVs(30, 10, 1, 12, 28, 13)
                     },
                     mark: Some(Mark::Uncommon)
@@ -573,4 +1430,477 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_shiny_type() {
+        let state = new_state(true, EncounterMethod::Static);
+
+        // Shinies whose PID had to be fixed up always come out square.
+        let trainer = new_trainer();
+        let spawns = state.take(5000).collect::<Vec<_>>();
+        let shinies = spawns
+            .iter()
+            .filter(|s| s.dynamic.shiny != Shininess::None)
+            .collect::<Vec<_>>();
+        assert!(!shinies.is_empty());
+        assert!(shinies.iter().any(|s| s.dynamic.shiny == Shininess::Square));
+
+        // The final PID agrees with the shiny roll for the trainer's TSV.
+        for spawn in spawns.iter() {
+            assert_eq!(
+                trainer.is_shiny(spawn.dynamic.pid),
+                spawn.dynamic.shiny != Shininess::None
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_seed() {
+        assert_eq!(parse_seed("5e5c928d61792fed"), Ok(0x5e5c928d61792fed));
+        assert_eq!(parse_seed(" 0xED608999E1410AA9 "), Ok(0xed608999e1410aa9));
+        assert!(parse_seed("").is_err());
+        assert!(parse_seed("0x1ffffffffffffffff").is_err());
+    }
+
+    #[test]
+    fn test_search() {
+        use super::super::filter::{MarkFilter, ShinyFilter};
+
+        let new_search_state = || {
+            let mut state = new_state(
+                true,
+                EncounterMethod::Symbol {
+                    level: Level::new(20, 25),
+                    diff_held_item: false,
+                },
+            );
+            state.weather = Weather::Overcast;
+            state
+        };
+
+        // The first square shiny is the first square in a plain listing.
+        let filter = SpawnFilter::new().set_shiny(ShinyFilter::Square);
+        let expected = new_search_state()
+            .take(10_000)
+            .find(|s| s.dynamic.shiny == Shininess::Square)
+            .unwrap();
+        let mut state = new_search_state();
+        let found = state.search(&filter, 10_000).unwrap();
+        assert_eq!(found, expected);
+        assert_eq!(state.advances, found.advance + 1);
+
+        // Too small a budget finds nothing.
+        assert_eq!(
+            new_search_state().search(&filter, found.advance as usize),
+            None
+        );
+
+        // Every match satisfies every criterion.
+        let filter = SpawnFilter::new()
+            .set_mark(MarkFilter::Personality)
+            .set_level(24, 25)
+            .set_slot(0, 49);
+        let found = new_search_state().find_all(&filter, 10_000);
+        assert!(!found.is_empty());
+        for spawn in found.iter() {
+            assert!(matches!(spawn.mark, Some(Mark::Personality(_))));
+            assert!(spawn.level().unwrap() >= 24);
+            assert!(spawn.slot().unwrap() < 50);
+        }
+
+        // A specific personality mark narrows the matches down further.
+        let mark = match found[0].mark {
+            Some(Mark::Personality(mark)) => mark,
+            _ => unreachable!(),
+        };
+        let mut filter = filter;
+        let specific = new_search_state().find_all(&filter.set_personality_mark(mark), 10_000);
+        assert!(specific.contains(&found[0]));
+        assert!(specific
+            .iter()
+            .all(|s| s.mark == Some(Mark::Personality(mark))));
+
+        // Static encounters never pass slot filters.
+        let mut state = new_search_state();
+        state.encounter = EncounterMethod::Static;
+        assert_eq!(state.search(&SpawnFilter::new().set_slot(0, 99), 100), None);
+    }
+
+    #[test]
+    fn test_weather_and_time_marks() {
+        use super::super::filter::MarkFilter;
+
+        let new_weather_state = |weather| {
+            let mut state = new_static_state();
+            state.player = Player::new(&new_trainer(), false, true);
+            state.weather = weather;
+            state.time = TimeOfDay::Night;
+            state
+        };
+
+        // The mark matches the current weather and time exactly.
+        let filter = SpawnFilter::new().set_mark(MarkFilter::Weather);
+        let spawns = new_weather_state(Weather::Sandstorm).find_all(&filter, 2000);
+        assert!(!spawns.is_empty());
+        assert!(spawns
+            .iter()
+            .all(|s| s.mark == Some(Mark::Weather(Weather::Sandstorm))));
+        assert_eq!(spawns[0].mark().unwrap(), "Sandstorm");
+
+        let filter = SpawnFilter::new().set_mark(MarkFilter::Time);
+        let spawns = new_weather_state(Weather::Sandstorm).find_all(&filter, 2000);
+        assert!(!spawns.is_empty());
+        assert!(spawns
+            .iter()
+            .all(|s| s.mark == Some(Mark::Time(TimeOfDay::Night))));
+        assert_eq!(spawns[0].mark().unwrap(), "Sleepy-Time");
+
+        // Clear skies never award a weather mark.
+        let filter = SpawnFilter::new().set_mark(MarkFilter::Weather);
+        assert!(new_weather_state(Weather::Clear)
+            .find_all(&filter, 2000)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_held_item() {
+        let new_item_state = |diff_held_item| {
+            new_state(
+                false,
+                EncounterMethod::Symbol {
+                    level: Level::new(60, 60),
+                    diff_held_item,
+                },
+            )
+        };
+
+        // Without differing items, the common item is held if the species has one.
+        assert!(new_item_state(false)
+            .take(100)
+            .all(|s| s.held_item().is_none()));
+        let mut state = new_item_state(false);
+        state.set_species(710, 3);
+        assert!(state
+            .take(100)
+            .all(|s| s.held_item() == Some(HeldItem::Common)));
+        let mut state = new_item_state(false);
+        state.set_species(710, 0);
+        assert!(state.take(100).all(|s| s.held_item().is_none()));
+
+        // Otherwise, each slot shows up, and the filter picks out one.
+        let spawns = new_item_state(true).take(1000).collect::<Vec<_>>();
+        for &item in [HeldItem::None, HeldItem::Common, HeldItem::Rare].iter() {
+            assert!(spawns.iter().any(|s| s.held_item() == Some(item)));
+        }
+        let filter = SpawnFilter::new().set_held_item(HeldItem::Rare);
+        let rare = new_item_state(true).find_all(&filter, 1000);
+        assert_eq!(
+            rare.len(),
+            spawns
+                .iter()
+                .filter(|s| s.held_item() == Some(HeldItem::Rare))
+                .count()
+        );
+
+        // Item IDs need the species from the slot.
+        assert_eq!(rare[0].get_held_item_id(), None);
+        let held_item_ids = |species, alt_form, item| {
+            let mut state = new_item_state(true);
+            state.set_slot_table(SlotTable::new(
+                "Test".to_string(),
+                SlotKind::Symbol,
+                None,
+                vec![EncounterSlot::new(
+                    species,
+                    alt_form,
+                    100,
+                    Level::new(60, 60),
+                )],
+            ));
+            let filter = SpawnFilter::new().set_held_item(item);
+            state
+                .find_all(&filter, 1000)
+                .iter()
+                .map(|s| s.get_held_item_id())
+                .collect::<Vec<_>>()
+        };
+
+        // Item IDs come from personal data: super size Pumpkaboo has item 239 in both slots.
+        let ids = held_item_ids(710, 3, HeldItem::Rare);
+        assert!(!ids.is_empty());
+        assert!(ids.iter().all(|&id| id == Some(239)));
+        assert!(held_item_ids(710, 0, HeldItem::Rare)
+            .iter()
+            .all(|id| id.is_none()));
+        // Farfetch'd only holds a Leek, as its rare item.
+        assert!(held_item_ids(83, 0, HeldItem::Rare)
+            .iter()
+            .all(|&id| id == Some(259)));
+        assert!(held_item_ids(83, 0, HeldItem::Common)
+            .iter()
+            .all(|id| id.is_none()));
+    }
+
+    #[test]
+    fn test_gender_and_size() {
+        use super::super::filter::GenderFilter;
+
+        // Without a species, gender is unknown, and gender filters never pass.
+        let filter = SpawnFilter::new().set_gender(GenderFilter::Female);
+        assert!(new_static_state().take(100).all(|s| s.gender().is_none()));
+        assert_eq!(new_static_state().search(&filter, 100), None);
+
+        // Setting a species never changes the rest of the spawn, only the gender reported.
+        let expected = new_static_state().take(100).collect::<Vec<_>>();
+        for &(species, gender) in [
+            (132, Some(Gender::Genderless)), // Ditto.
+            (29, Some(Gender::Female)),      // Nidoran♀.
+            (32, Some(Gender::Male)),        // Nidoran♂.
+        ]
+        .iter()
+        {
+            let mut state = new_static_state();
+            state.set_species(species, 0);
+            for (spawn, expected) in state.take(100).zip(expected.iter()) {
+                assert_eq!(spawn.gender(), gender);
+                assert_eq!(
+                    DynamicStats {
+                        gender: None,
+                        ..spawn.dynamic
+                    },
+                    expected.dynamic
+                );
+            }
+        }
+
+        // Species with a ratio take their gender from the roll, and the filter matches it.
+        let mut state = new_static_state();
+        state.set_species(133, 0); // Eevee, 7:1 male to female.
+        let spawns = state.take(100).collect::<Vec<_>>();
+        for &gender in [Gender::Male, Gender::Female].iter() {
+            assert!(spawns.iter().any(|s| s.gender() == Some(gender)));
+        }
+        let mut state = new_static_state();
+        state.set_species(133, 0);
+        assert_eq!(
+            state.find_all(&filter, 100),
+            spawns
+                .into_iter()
+                .filter(|s| s.gender() == Some(Gender::Female))
+                .collect::<Vec<_>>()
+        );
+
+        // Height and weight come from the fixed seed, so filters match the listing.
+        let filter = SpawnFilter::new()
+            .set_height(0, 0x20)
+            .set_weight(0xe0, 0xff);
+        let found = new_static_state().find_all(&filter, 5000);
+        let expected = new_static_state()
+            .take(5000)
+            .filter(|s| s.height() <= 0x20 && s.weight() >= 0xe0)
+            .collect::<Vec<_>>();
+        assert!(!found.is_empty());
+        assert_eq!(found, expected);
+        assert!(new_static_state().take(5000).all(|s| s.height() <= 0xff));
+    }
+
+    #[test]
+    fn test_ko_bonus() {
+        assert_eq!(get_ko_bonus(0).brilliant_rate, 0);
+        assert_eq!(get_ko_bonus(1).brilliant_rate, 10);
+        assert_eq!(get_ko_bonus(49).shiny_rolls, 0);
+        assert_eq!(get_ko_bonus(50).shiny_rolls, 1);
+        assert_eq!(get_ko_bonus(499).shiny_rolls, 4);
+        assert_eq!(get_ko_bonus(9999).shiny_rolls, 5);
+    }
+
+    #[test]
+    fn test_ko_count() {
+        let new_ko_state = |ko_count| {
+            let mut state = new_state(
+                false,
+                EncounterMethod::Symbol {
+                    level: Level::new(60, 60),
+                    diff_held_item: false,
+                },
+            );
+            state.set_ko_count(ko_count);
+            state
+        };
+
+        // Without battles, nothing is brilliant.
+        assert!(new_ko_state(0)
+            .take(5000)
+            .all(|s| s.brilliant() == Some(false)));
+
+        // Brilliant spawns get guaranteed flawless IVs.
+        let filter = SpawnFilter::new().set_brilliant(true);
+        let brilliant = new_ko_state(500).find_all(&filter, 5000);
+        assert!(!brilliant.is_empty());
+        for spawn in brilliant.iter() {
+            assert!(spawn.brilliant_roll().unwrap() < 30);
+            assert!(count_flawless(spawn.ivs()) >= 4);
+        }
+
+        // More battles means more shiny rolls, so never fewer shinies.
+        let count_shinies = |ko_count| {
+            new_ko_state(ko_count)
+                .take(20_000)
+                .filter(|s| s.shiny() != Shininess::None)
+                .count()
+        };
+        assert!(count_shinies(500) > count_shinies(0));
+    }
+
+    #[test]
+    fn test_fixed_encounter() {
+        let new_fixed_state = |name| {
+            let encounter = get_static_encounter(name).unwrap();
+            let mut state = new_state(true, EncounterMethod::Fixed(encounter));
+            state.set_species(encounter.get_species(), encounter.get_alt_form());
+            state
+        };
+
+        // Legendaries get three flawless IVs and no marks.
+        for spawn in new_fixed_state("Zacian").take(5000) {
+            assert!(count_flawless(spawn.ivs()) >= 3);
+            assert_eq!(spawn.mark(), None);
+            assert_eq!(spawn.ability(), Ability::First);
+            // Zacian is shiny locked.
+            assert_eq!(spawn.shiny(), Shininess::None);
+        }
+
+        // Unlocked encounters still spawn shiny, and agree with the unlocked generator.
+        let mut unlocked = new_fixed_state("Dracozolt");
+        let mut plain = new_fixed_state("Dracozolt");
+        plain.encounter = EncounterMethod::Static;
+        let filter = SpawnFilter::new().set_shiny(ShinyFilter::Shiny);
+        let shiny = unlocked.search(&filter, 20_000).unwrap();
+        assert_eq!(
+            plain.nth(shiny.advance as usize).unwrap().pid(),
+            shiny.pid()
+        );
+    }
+
+    #[test]
+    fn test_slot_table() {
+        let mut state = new_state(
+            false,
+            EncounterMethod::Symbol {
+                level: Level::new(1, 1),
+                diff_held_item: false,
+            },
+        );
+        let table = SlotTable::new(
+            "Test".to_string(),
+            SlotKind::Symbol,
+            None,
+            vec![
+                EncounterSlot::new(819, 0, 60, Level::new(2, 5)),
+                EncounterSlot::new(831, 0, 40, Level::new(3, 5)),
+            ],
+        );
+        state.set_slot_table(table.clone());
+
+        // Each spawn gets the species and level range of its slot.
+        for spawn in state.by_ref().take(1000) {
+            let entry = table.get_slot(spawn.slot().unwrap()).unwrap();
+            assert_eq!(spawn.species(), Some(entry.species()));
+            assert_eq!(spawn.alt_form(), Some(entry.alt_form()));
+            let level = spawn.level().unwrap();
+            assert!(entry.get_level().min <= level && level <= entry.get_level().max);
+        }
+
+        let filter = SpawnFilter::new().set_species(831);
+        let wooloo = state.find_all(&filter, 1000);
+        assert!(!wooloo.is_empty());
+        assert!(wooloo.iter().all(|spawn| spawn.species() == Some(831)));
+
+        // Without a table, the species is unknown and never passes the filter.
+        state.slots = None;
+        assert!(state.find_all(&filter, 1000).is_empty());
+    }
+
+    #[test]
+    fn test_encounter_paths() {
+        let level = Level::new(10, 15);
+
+        // Symbol, hidden and fishing encounters share the reference's RNG calls, so they only
+        // differ in the marks they can get.
+        let symbol = new_state(
+            false,
+            EncounterMethod::Symbol {
+                level,
+                diff_held_item: true,
+            },
+        );
+        let hidden = new_state(
+            false,
+            EncounterMethod::Hidden {
+                level,
+                diff_held_item: true,
+            },
+        );
+        let fishing = new_state(
+            false,
+            EncounterMethod::Fishing {
+                level,
+                diff_held_item: true,
+            },
+        );
+        for ((symbol, hidden), fishing) in symbol.zip(hidden).zip(fishing).take(1000) {
+            assert_eq!(symbol, hidden);
+            assert_eq!(symbol.spawn, fishing.spawn);
+            assert_eq!(symbol.dynamic, fishing.dynamic);
+            if fishing.mark != Some(Mark::Fishing) {
+                assert_eq!(symbol.mark, fishing.mark);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jump_to() {
+        use crate::core::tracker::AdvanceTracker;
+
+        // Blink on advances 500 to 539, then find where we are.
+        let mut rng = Rng::from_state(SEED.0, SEED.1);
+        for _ in 0..500 {
+            rng.next();
+        }
+        let mut tracker = AdvanceTracker::from_state(SEED);
+        for _ in 0..40 {
+            tracker.add_blink(rng.next() & 1 == 1);
+        }
+
+        let mut state = new_static_state();
+        state.jump_to(&tracker.find(1000).unwrap());
+        let spawn = state.next().unwrap();
+        assert_eq!(spawn.advance, 540);
+        assert_eq!(spawn, new_static_state().nth(540).unwrap());
+    }
+
+    #[test]
+    fn test_menu_closes() {
+        let mut state = new_static_state();
+        state.set_advance_model(AdvanceModel::new(4));
+
+        // Each tick takes an advance for the player and each NPC.
+        state.nth(99);
+        assert_eq!(state.advances, 500);
+
+        let plan = state.plan_menu_closes(2000.0).unwrap();
+        for _ in 0..plan.get_menu_closes() {
+            state.close_menu();
+        }
+        assert_eq!(state.advances + plan.get_raw_advances(), 2000);
+
+        // Waiting out the raw advances lands on the same spawn as advancing one at a time.
+        let ticks = plan.get_raw_advances() / 5;
+        let spawn = state.nth(ticks as usize).unwrap();
+        assert_eq!(spawn, new_static_state().nth(2000).unwrap());
+
+        assert_eq!(state.plan_menu_closes(100.0), None);
+        assert_eq!(state.plan_menu_closes(f64::NAN), None);
+        assert_eq!(state.plan_menu_closes(f64::INFINITY), None);
+    }
 }
@@ -1,4 +1,5 @@
 ///! Pokedex-type data.
+use super::mon::{Ability, HeldItem, Stats};
 use std::fmt;
 use std::fs::File;
 use std::io;
@@ -14,8 +15,8 @@ pub struct Mon {
     spe: u8,
     spa: u8,
     spd: u8,
+    /// Common and rare held items.
     item: (u16, u16),
-    /// Held item.
     gender_ratio: u8,
     ability_1: u16,
     ability_2: u16,
@@ -29,6 +30,28 @@ pub struct Mon {
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub struct Abilities(pub u16, pub u16, pub u16);
 
+#[wasm_bindgen]
+impl Abilities {
+    /// Returns the ability ID in the given slot.
+    pub fn get(&self, slot: Ability) -> u16 {
+        match slot {
+            Ability::First => self.0,
+            Ability::Second => self.1,
+            Ability::Hidden => self.2,
+        }
+    }
+
+    /// Returns true if the ability in the given slot also occupies another slot.
+    /// For example, a mon whose HA is the same as its first ability gains nothing
+    /// from an HA filter.
+    pub fn is_shared(&self, slot: Ability) -> bool {
+        let id = self.get(slot);
+        [Ability::First, Ability::Second, Ability::Hidden]
+            .iter()
+            .any(|&other| other != slot && self.get(other) == id)
+    }
+}
+
 // 176 bytes per mon.
 const ENTRY_SIZE: usize = 0xB0;
 
@@ -75,7 +98,10 @@ impl Mon {
             spe: buf[3],
             spa: buf[4],
             spd: buf[5],
-            item: (buf[12] as u16, buf[14] as u16),
+            item: (
+                u16::from_le_bytes([buf[12], buf[13]]),
+                u16::from_le_bytes([buf[14], buf[15]]),
+            ),
             gender_ratio: buf[18],
             ability_1: (buf[25] as u16) << 8 | buf[24] as u16,
             ability_2: (buf[27] as u16) << 8 | buf[26] as u16,
@@ -87,6 +113,27 @@ impl Mon {
     }
 }
 
+impl Mon {
+    /// Returns the item ID in the given held item slot, or None if the slot is empty.
+    pub fn get_held_item(&self, slot: HeldItem) -> Option<u16> {
+        let item = match slot {
+            HeldItem::None => 0,
+            HeldItem::Common => self.item.0,
+            HeldItem::Rare => self.item.1,
+        };
+        if item == 0 {
+            None
+        } else {
+            Some(item)
+        }
+    }
+
+    /// Returns true if the common and rare held items differ, so the game rolls for them.
+    pub fn has_diff_held_items(&self) -> bool {
+        self.item.0 != self.item.1
+    }
+}
+
 impl fmt::Display for Mon {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -112,6 +159,18 @@ impl fmt::Display for Mon {
 
 #[wasm_bindgen]
 impl Mon {
+    /// Returns base stats, in the same order as IVs.
+    pub fn get_base_stats(&self) -> Stats {
+        Stats(
+            self.hp as u32,
+            self.atk as u32,
+            self.def as u32,
+            self.spa as u32,
+            self.spd as u32,
+            self.spe as u32,
+        )
+    }
+
     /// Returns abilities.
     pub fn get_abilities(&self) -> Abilities {
         Abilities(self.ability_1, self.ability_2, self.ability_hidden)
@@ -131,6 +190,11 @@ impl Mon {
     pub fn get_form_count(&self) -> u8 {
         self.form_count
     }
+
+    /// Returns true if the mon is present in Sword/Shield.
+    pub fn is_included(&self) -> bool {
+        self.included
+    }
 }
 
 #[wasm_bindgen]
@@ -191,6 +255,7 @@ impl fmt::Display for PersonalTable {
 
 #[cfg(test)]
 mod test {
+    use super::super::super::personal_data::PERSONAL_DATA;
     use super::*;
 
     #[test]
@@ -269,6 +334,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_abilities() {
+        let table = PersonalTable::from_bytes("resources/personal").unwrap();
+
+        // Bulbasaur: Overgrow / Overgrow / Chlorophyll.
+        let abilities = table.get_info(1, 0).unwrap().get_abilities();
+        assert_eq!(abilities.get(Ability::First), 65);
+        assert_eq!(abilities.get(Ability::Hidden), 34);
+        assert!(abilities.is_shared(Ability::First));
+        assert!(abilities.is_shared(Ability::Second));
+        assert!(!abilities.is_shared(Ability::Hidden));
+
+        // Eternatus: Pressure in every slot.
+        let abilities = table.get_info(890, 0).unwrap().get_abilities();
+        assert!(abilities.is_shared(Ability::Hidden));
+    }
+
     #[test]
     fn test_forms() {
         let table = PersonalTable::from_bytes("resources/personal").unwrap();
@@ -338,5 +420,28 @@ mod test {
 
         // Nonexistent size.
         assert_eq!(table.get_info(710, 4), None);
+
+        // Only the super size holds an item, in both slots.
+        let super_size = table.get_info(710, 3).unwrap();
+        assert_eq!(super_size.get_held_item(HeldItem::Common), Some(239));
+        assert_eq!(super_size.get_held_item(HeldItem::Rare), Some(239));
+        assert_eq!(super_size.get_held_item(HeldItem::None), None);
+        assert!(!super_size.has_diff_held_items());
+        assert_eq!(pumpkaboo.unwrap().get_held_item(HeldItem::Common), None);
+    }
+
+    #[test]
+    fn test_held_items() {
+        // The generated table matches the binary it was generated from.
+        let table = PersonalTable::from_bytes("resources/personal").unwrap();
+        assert_eq!(*PERSONAL_DATA, table);
+
+        // Item IDs are 16 bits: Farfetch'd holds a Leek (259), and Sandshrew a Grip Claw (286).
+        let farfetchd = PERSONAL_DATA.get_info(83, 0).unwrap();
+        assert_eq!(farfetchd.get_held_item(HeldItem::Common), None);
+        assert_eq!(farfetchd.get_held_item(HeldItem::Rare), Some(259));
+        assert!(farfetchd.has_diff_held_items());
+        let sandshrew = PERSONAL_DATA.get_info(27, 0).unwrap();
+        assert_eq!(sandshrew.get_held_item(HeldItem::Rare), Some(286));
     }
 }
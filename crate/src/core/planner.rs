@@ -0,0 +1,400 @@
+//! Date-skip planning for reaching a target frame.
+//!
+//! Each date skip moves the system clock forward one day and advances the den seed by one frame.
+//! Moving the clock backwards does not advance the seed. A 3-day skip, done without saving and
+//! followed by a reset, shows the den three frames ahead so the path can be checked.
+use super::frame::Seed;
+use super::rng::Rng;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// A calendar date, as set in the console's system settings.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, PartialOrd, Ord)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+/// The earliest date the console allows.
+pub const MIN_DATE: Date = Date {
+    year: 2000,
+    month: 1,
+    day: 1,
+};
+
+/// The latest date the console allows.
+pub const MAX_DATE: Date = Date {
+    year: 2060,
+    month: 12,
+    day: 31,
+};
+
+/// The latest date a 3-day skip can start from.
+const MAX_DATE_BEFORE_VERIFY: Date = Date {
+    year: 2060,
+    month: 12,
+    day: 28,
+};
+
+#[wasm_bindgen]
+impl Date {
+    /// Creates a date, failing if the day doesn't exist or the console can't be set to it.
+    #[wasm_bindgen(constructor)]
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Date, JsValue> {
+        Date::from_ymd(year, month, day).map_err(JsValue::from)
+    }
+
+    pub fn get_year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn get_month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn get_day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the following day.
+    pub fn next_day(&self) -> Date {
+        if self.day < days_in_month(self.year, self.month) {
+            Date {
+                day: self.day + 1,
+                ..*self
+            }
+        } else if self.month < 12 {
+            Date {
+                month: self.month + 1,
+                day: 1,
+                ..*self
+            }
+        } else {
+            Date {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+}
+
+impl Date {
+    /// Creates a date, failing if the day doesn't exist or the console can't be set to it.
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Result<Date, String> {
+        if (MIN_DATE.year..=MAX_DATE.year).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Ok(Date { year, month, day })
+        } else {
+            Err(format!("Invalid date: {:04}-{:02}-{:02}", year, month, day))
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// `is_multiple_of` needs a newer toolchain than the crate supports.
+#[allow(clippy::manual_is_multiple_of)]
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// What the player should do at a step of the plan.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum StepKind {
+    /// Skip the date forward one day at a time, `skips` times, ending on `date`.
+    Skip = 0,
+    /// Set the date back to `date`. This doesn't advance the frame.
+    SetDateBack = 1,
+    /// Save the game.
+    Save = 2,
+    /// Do a 3-day skip without saving, ending on `date`, and check the den shows `frame`.
+    /// Then close the game without saving to return to the saved frame.
+    Verify = 3,
+    /// The den is on the target frame and ready to host.
+    Host = 4,
+}
+
+/// A single step of a date-skip plan.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct PlanStep {
+    pub kind: StepKind,
+    /// The frame the step ends on, or for a verification, the frame to check.
+    pub frame: u32,
+    /// Number of date skips taken by the step.
+    pub skips: u32,
+    /// The system date at the end of the step.
+    pub date: Date,
+    seed: u64,
+}
+
+#[wasm_bindgen]
+impl PlanStep {
+    /// Returns the den seed at the step's frame.
+    pub fn get_seed(&self) -> Seed {
+        Seed::from_u64(self.seed)
+    }
+}
+
+impl fmt::Display for PlanStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            StepKind::Skip => write!(
+                f,
+                "Skip {} day(s) to {} (frame {})",
+                self.skips, self.date, self.frame
+            ),
+            StepKind::SetDateBack => write!(f, "Set the date back to {}", self.date),
+            StepKind::Save => write!(f, "Save (frame {})", self.frame),
+            StepKind::Verify => write!(
+                f,
+                "3-day skip to {} and check frame {} ({:#018x}), then reset",
+                self.date, self.frame, self.seed
+            ),
+            StepKind::Host => write!(f, "Host frame {} ({:#018x})", self.frame, self.seed),
+        }
+    }
+}
+
+/// Builds plans, keeping track of the frame, its seed and the date as steps are added.
+struct Planner {
+    /// The den seed at `frame`.
+    seed: u64,
+    frame: u32,
+    date: Date,
+    steps: Vec<PlanStep>,
+}
+
+impl Planner {
+    /// Adds a step ending on a frame at or after the current one.
+    fn push(&mut self, kind: StepKind, frame: u32, skips: u32) {
+        self.steps.push(PlanStep {
+            kind,
+            frame,
+            skips,
+            date: self.date,
+            seed: Rng::get_seed_at_offset(self.seed, (frame - self.frame) as usize),
+        });
+    }
+
+    /// Skips forward to a frame, setting the date back whenever the console's limit is reached.
+    fn skip_to(&mut self, frame: u32) {
+        let mut skips = 0;
+        while self.frame < frame {
+            if self.date >= MAX_DATE {
+                if skips > 0 {
+                    self.push(StepKind::Skip, self.frame, skips);
+                    skips = 0;
+                }
+                self.date = MIN_DATE;
+                self.push(StepKind::SetDateBack, self.frame, 0);
+            }
+            self.date = self.date.next_day();
+            self.seed = Rng::get_seed_at_offset(self.seed, 1);
+            self.frame += 1;
+            skips += 1;
+        }
+        if skips > 0 {
+            self.push(StepKind::Skip, self.frame, skips);
+        }
+    }
+
+    /// Saves, then checks the frame three ahead with a 3-day skip.
+    fn save_and_verify(&mut self) {
+        // Make room for the 3-day skip before saving.
+        if self.date > MAX_DATE_BEFORE_VERIFY {
+            self.date = MIN_DATE;
+            self.push(StepKind::SetDateBack, self.frame, 0);
+        }
+        self.push(StepKind::Save, self.frame, 0);
+        // The reset brings the frame back, but the clock stays where the 3-day skip left it.
+        for _ in 0..3 {
+            self.date = self.date.next_day();
+        }
+        self.push(StepKind::Verify, self.frame + 3, 3);
+    }
+}
+
+/// Plans the date skips needed to go from frame 0 of a seed to a target frame,
+/// starting on the given date.
+///
+/// When `checkpoint_interval` is nonzero, the plan also saves and verifies the frame every
+/// `checkpoint_interval` frames, so a missed or extra skip is caught early.
+pub fn plan_date_skips(
+    seed: u64,
+    target: u32,
+    date: Date,
+    checkpoint_interval: u32,
+) -> Vec<PlanStep> {
+    let mut planner = Planner {
+        seed,
+        frame: 0,
+        date,
+        steps: Vec::new(),
+    };
+
+    // Saving three frames early lets the target itself be checked with a 3-day skip.
+    let final_save = target.saturating_sub(3);
+    if checkpoint_interval > 0 {
+        let mut checkpoint = checkpoint_interval;
+        while checkpoint < final_save {
+            planner.skip_to(checkpoint);
+            planner.save_and_verify();
+            checkpoint += checkpoint_interval;
+        }
+    }
+    planner.skip_to(final_save);
+    if target >= 3 {
+        planner.save_and_verify();
+    }
+    planner.skip_to(target);
+    planner.push(StepKind::Host, target, 0);
+
+    planner.steps
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> Date {
+        Date::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_next_day() {
+        assert_eq!(date(2020, 2, 28).next_day(), date(2020, 2, 29));
+        assert_eq!(date(2021, 2, 28).next_day(), date(2021, 3, 1));
+        assert_eq!(date(2020, 12, 31).next_day(), date(2021, 1, 1));
+        assert_eq!(date(2020, 4, 30).next_day(), date(2020, 5, 1));
+        assert_eq!(format!("{}", date(2020, 4, 3)), "2020-04-03");
+    }
+
+    #[test]
+    fn test_invalid_date() {
+        assert!(Date::from_ymd(2020, 2, 29).is_ok());
+        assert!(Date::from_ymd(2021, 2, 29).is_err());
+        assert!(Date::from_ymd(2000, 2, 29).is_ok());
+        assert!(!is_leap_year(2100));
+        assert!(Date::from_ymd(2020, 4, 31).is_err());
+        assert!(Date::from_ymd(2020, 13, 1).is_err());
+        assert!(Date::from_ymd(2020, 1, 0).is_err());
+
+        // The console only allows dates from 2000 to 2060.
+        assert!(Date::from_ymd(2000, 1, 1).is_ok());
+        assert!(Date::from_ymd(2060, 12, 31).is_ok());
+        assert!(Date::from_ymd(1999, 12, 31).is_err());
+        assert!(Date::from_ymd(2061, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_plan() {
+        let seed = 0xbb810e6006a2a035;
+        let steps = plan_date_skips(seed, 10, date(2020, 12, 30), 0);
+        let kinds = steps.iter().map(|s| s.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                StepKind::Skip,
+                StepKind::Save,
+                StepKind::Verify,
+                StepKind::Skip,
+                StepKind::Host
+            ]
+        );
+
+        assert_eq!(steps[0].skips, 7);
+        assert_eq!(steps[0].date, date(2021, 1, 6));
+        assert_eq!(steps[1].frame, 7);
+        assert_eq!(steps[2].frame, 10);
+        assert_eq!(steps[2].date, date(2021, 1, 9));
+        // After the reset, skipping continues from the date the 3-day skip left behind.
+        assert_eq!(steps[3].skips, 3);
+        assert_eq!(steps[3].date, date(2021, 1, 12));
+        assert_eq!(steps[4].frame, 10);
+        for step in &steps {
+            assert_eq!(
+                step.seed,
+                Rng::get_seed_at_offset(seed, step.frame as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_plan_short() {
+        let steps = plan_date_skips(0, 2, date(2020, 1, 1), 0);
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].kind, StepKind::Skip);
+        assert_eq!(steps[0].skips, 2);
+        assert_eq!(steps[1].kind, StepKind::Host);
+
+        let steps = plan_date_skips(0, 0, date(2020, 1, 1), 0);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].kind, StepKind::Host);
+    }
+
+    #[test]
+    fn test_plan_checkpoints() {
+        let steps = plan_date_skips(0, 25, date(2020, 1, 1), 10);
+        let verified = steps
+            .iter()
+            .filter(|s| s.kind == StepKind::Verify)
+            .map(|s| s.frame)
+            .collect::<Vec<_>>();
+        assert_eq!(verified, vec![13, 23, 25]);
+
+        // Every frame is reached by exactly the number of skips planned.
+        let skips: u32 = steps
+            .iter()
+            .filter(|s| s.kind == StepKind::Skip)
+            .map(|s| s.skips)
+            .sum();
+        assert_eq!(skips, 25);
+    }
+
+    #[test]
+    fn test_plan_date_limit() {
+        let steps = plan_date_skips(0, 4, date(2060, 12, 30), 0);
+        let kinds = steps.iter().map(|s| s.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                StepKind::Skip,
+                StepKind::SetDateBack,
+                StepKind::Save,
+                StepKind::Verify,
+                StepKind::Skip,
+                StepKind::Host
+            ]
+        );
+        assert_eq!(steps[0].date, MAX_DATE);
+        assert_eq!(steps[1].date, MIN_DATE);
+        assert_eq!(steps[3].date, date(2000, 1, 4));
+        assert_eq!(steps[4].date, date(2000, 1, 7));
+
+        // Long skips set the date back whenever the limit is reached.
+        let steps = plan_date_skips(0, 1, MAX_DATE, 0);
+        assert_eq!(steps[0].kind, StepKind::SetDateBack);
+        assert_eq!(steps[1].date, date(2000, 1, 2));
+    }
+}
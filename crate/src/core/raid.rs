@@ -1,6 +1,9 @@
 ///! Raid generation parameters.
+use super::super::den_data::DEN_DATA;
 use super::super::personal_data::get_personal_info;
-use super::mon::{Ability, Gender};
+use super::den::GameVersion;
+use super::mon::{Ability, Gender, Stats};
+use super::personal::Abilities;
 use wasm_bindgen::prelude::*;
 
 /// Species of mon.
@@ -34,14 +37,14 @@ pub enum AbilityPool {
 
 impl From<u8> for AbilityPool {
     // Converts a u32 into a AbilityPool, based on the game's encoding.
+    // Unknown values come from bad input, so they fall back to a random ability.
     fn from(n: u8) -> Self {
         match n {
-            n if n == 4 => AbilityPool::Random,
             n if n == 3 => AbilityPool::NoHA,
             n if n == 2 => AbilityPool::Locked(Ability::Hidden),
             n if n == 1 => AbilityPool::Locked(Ability::Second),
             n if n == 0 => AbilityPool::Locked(Ability::First),
-            _ => panic!("Invalid ability type"),
+            _ => AbilityPool::Random,
         }
     }
 }
@@ -55,13 +58,14 @@ pub enum GenderPool {
 
 impl GenderPool {
     // Converts a u32 into a GenderPool, based on the game's encoding.
+    // Unknown values fall back to a random gender, and a species without personal info has
+    // no ratio to roll against, so it is treated as genderless.
     fn from(n: u8, ratio: Option<u8>) -> Self {
         match n {
-            n if n == 0 => GenderPool::Random(ratio.unwrap()),
             n if n == 1 => GenderPool::Locked(Gender::Male),
             n if n == 2 => GenderPool::Locked(Gender::Female),
             n if n == 3 => GenderPool::Locked(Gender::Genderless),
-            _ => panic!("Invalid gender type"),
+            _ => ratio.map_or(GenderPool::Locked(Gender::Genderless), GenderPool::Random),
         }
     }
 }
@@ -78,11 +82,13 @@ pub struct Raid {
     ability: AbilityPool,
     gender: GenderPool,
     shiny: ShinyPool,
+    stars: u8,
 }
 
 #[wasm_bindgen]
 impl Raid {
     #[wasm_bindgen(constructor)]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         species: Species,
         alt_form: u8,
@@ -90,7 +96,12 @@ impl Raid {
         is_gmax: bool,
         ability_pool: u8,
         gender_pool: u8,
+        shiny_pool: u8,
+        stars: u8,
     ) -> Self {
+        // Star ratings outside 1-5 come from bad input, so clamp them rather than abort.
+        let stars = stars.clamp(1, 5);
+
         let gender_ratio =
             get_personal_info(species as usize, alt_form as usize).map(|pi| pi.get_gender_ratio());
 
@@ -101,9 +112,19 @@ impl Raid {
             is_gmax,
             ability: AbilityPool::from(ability_pool),
             gender: GenderPool::from(gender_pool, gender_ratio),
-            shiny: ShinyPool::Random,
+            shiny: ShinyPool::from(shiny_pool),
+            stars,
         }
     }
+
+    /// Creates a raid from a den's encounter table, at the highest star rating the encounter
+    /// appears at. Returns None if the den or entry does not exist.
+    pub fn from_den(den: usize, version: GameVersion, entry: usize) -> Option<Raid> {
+        let encounter = DEN_DATA.get_den(den)?.get_encounters(version).get(entry)?;
+        encounter
+            .get_max_stars()
+            .map(|stars| encounter.create_raid(stars))
+    }
 }
 
 impl Raid {
@@ -130,4 +151,92 @@ impl Raid {
     pub fn get_alt_form(&self) -> u8 {
         self.alt_form
     }
+
+    pub fn get_stars(&self) -> u8 {
+        self.stars
+    }
+
+    pub fn is_gmax(&self) -> bool {
+        self.is_gmax
+    }
+
+    /// Returns the range of levels the raid mon can be caught at, inclusive.
+    /// Each star rating spans five levels: 1★ is 15-20, 2★ is 25-30, and so on up to 5★ at 55-60.
+    pub fn get_level_range(&self) -> (u32, u32) {
+        let min = 5 + 10 * self.stars as u32;
+        (min, min + 5)
+    }
+
+    /// Returns the ability IDs of the raid mon, if it has personal data.
+    pub fn get_abilities(&self) -> Option<Abilities> {
+        get_personal_info(self.species as usize, self.alt_form as usize)
+            .map(|pi| pi.get_abilities())
+    }
+
+    /// Returns the base stats of the raid mon, if it has personal data.
+    pub fn get_base_stats(&self) -> Option<Stats> {
+        get_personal_info(self.species as usize, self.alt_form as usize)
+            .map(|pi| pi.get_base_stats())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shiny_pool() {
+        assert_eq!(ShinyPool::from(0), ShinyPool::Random);
+        assert_eq!(ShinyPool::from(1), ShinyPool::Locked(false));
+        assert_eq!(ShinyPool::from(2), ShinyPool::Locked(true));
+    }
+
+    #[test]
+    fn test_from_den() {
+        // Den 166, Sword: 5* Cradily.
+        assert_eq!(
+            Raid::from_den(166, GameVersion::Sword, 11),
+            Some(Raid::new(346, 0, 4, false, 4, 0, 0, 5))
+        );
+
+        // Den 166, Shield: 4-5* Cradily.
+        assert_eq!(
+            Raid::from_den(166, GameVersion::Shield, 7),
+            Some(Raid::new(346, 0, 4, false, 4, 0, 0, 5))
+        );
+
+        // Nonexistent entries and dens.
+        assert_eq!(Raid::from_den(166, GameVersion::Sword, 12), None);
+        assert_eq!(Raid::from_den(0, GameVersion::Sword, 0), None);
+        assert_eq!(Raid::from_den(198, GameVersion::Sword, 0), None);
+    }
+
+    #[test]
+    fn test_raid_shiny_pool() {
+        let raid = Raid::new(40, 0, 4, false, 2, 0, 1, 4);
+        assert_eq!(raid.get_shiny_pool(), ShinyPool::Locked(false));
+
+        let raid = Raid::new(40, 0, 4, false, 2, 0, 2, 4);
+        assert_eq!(raid.get_shiny_pool(), ShinyPool::Locked(true));
+    }
+
+    #[test]
+    fn test_stars_clamped() {
+        assert_eq!(Raid::new(40, 0, 4, false, 2, 0, 0, 0).get_stars(), 1);
+        assert_eq!(Raid::new(40, 0, 4, false, 2, 0, 0, 9).get_stars(), 5);
+    }
+
+    #[test]
+    fn test_invalid_pools() {
+        // Unknown pool values and species fall back instead of panicking.
+        let raid = Raid::new(40, 0, 4, false, 9, 9, 0, 4);
+        assert_eq!(raid.get_ability_pool(), AbilityPool::Random);
+        assert!(matches!(raid.get_gender_pool(), GenderPool::Random(_)));
+
+        let raid = Raid::new(9999, 0, 4, false, 4, 0, 0, 4);
+        assert_eq!(
+            raid.get_gender_pool(),
+            GenderPool::Locked(Gender::Genderless)
+        );
+    }
 }
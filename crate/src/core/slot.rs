@@ -0,0 +1,469 @@
+//! Overworld encounter slot tables, loaded from JSON dumps of the game's encounter tables.
+//!
+//! Each area has a table per kind of encounter, and some areas have a different table for each
+//! kind of weather. A spawn's slot roll (0-99) picks an entry by walking the cumulative rates.
+//!
+//! The expected format is:
+//!
+//! ```json
+//! {
+//!   "Tables": [
+//!     {
+//!       "Area": "Route 1",
+//!       "Kind": 0,
+//!       "Weather": 2,
+//!       "Slots": [
+//!         { "Species": 819, "AltForm": 0, "Rate": 40, "MinLevel": 2, "MaxLevel": 5 }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `Kind` and `Weather` use the values of `SlotKind` and `Weather`. A missing or null `Weather`
+//! means the area uses the same table in every weather. Each table's rates must add up to 100, so
+//! that every slot roll maps to an entry.
+use super::dump::{validate_species, EntryError};
+use super::mon::Weather;
+use super::overworld::Level;
+use serde::Deserialize;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Kind of wild encounter a table is used for.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum SlotKind {
+    /// Visible encounters wandering the overworld.
+    Symbol = 0,
+    /// Encounters hidden in rustling grass.
+    Hidden = 1,
+    Fishing = 2,
+}
+
+/// A single entry in an encounter table.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct EncounterSlot {
+    species: u32,
+    alt_form: u8,
+    /// Percent chance of the entry being picked.
+    rate: u32,
+    level: Level,
+}
+
+impl EncounterSlot {
+    pub fn new(species: u32, alt_form: u8, rate: u32, level: Level) -> Self {
+        EncounterSlot {
+            species,
+            alt_form,
+            rate,
+            level,
+        }
+    }
+
+    pub fn get_level(&self) -> &Level {
+        &self.level
+    }
+}
+
+#[wasm_bindgen]
+impl EncounterSlot {
+    #[wasm_bindgen(getter)]
+    pub fn species(&self) -> u32 {
+        self.species
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn alt_form(&self) -> u8 {
+        self.alt_form
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rate(&self) -> u32 {
+        self.rate
+    }
+}
+
+/// The encounter table for one area, kind of encounter and weather.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SlotTable {
+    area: String,
+    kind: SlotKind,
+    /// Weather the table is used in, or `None` if the area ignores the weather.
+    weather: Option<Weather>,
+    slots: Vec<EncounterSlot>,
+}
+
+impl SlotTable {
+    pub fn new(
+        area: String,
+        kind: SlotKind,
+        weather: Option<Weather>,
+        slots: Vec<EncounterSlot>,
+    ) -> Self {
+        SlotTable {
+            area,
+            kind,
+            weather,
+            slots,
+        }
+    }
+
+    pub fn get_area(&self) -> &str {
+        &self.area
+    }
+
+    pub fn get_slots(&self) -> &[EncounterSlot] {
+        &self.slots
+    }
+
+    /// Whether the table is used for the given area, kind of encounter and weather.
+    // `is_none_or` needs a newer toolchain than the crate supports.
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn is_used_for(&self, area: &str, kind: SlotKind, weather: Weather) -> bool {
+        self.area == area && self.kind == kind && self.weather.map_or(true, |w| w == weather)
+    }
+
+    /// Returns the entry picked by a slot roll, if the rates cover it.
+    pub fn get_slot(&self, roll: u32) -> Option<&EncounterSlot> {
+        let mut total = 0;
+        self.slots.iter().find(|slot| {
+            total += slot.rate;
+            roll < total
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SlotDump {
+    tables: Vec<TableEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TableEntry {
+    area: String,
+    kind: u8,
+    weather: Option<u8>,
+    slots: Vec<SlotEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SlotEntry {
+    species: u32,
+    alt_form: u8,
+    rate: u32,
+    min_level: u32,
+    max_level: u32,
+}
+
+/// Reasons a slot table dump could not be loaded.
+#[derive(Debug)]
+pub enum SlotError {
+    /// The dump is not valid JSON, or is missing fields.
+    Json(serde_json::Error),
+    InvalidKind(u8),
+    InvalidWeather(u8),
+    /// A slot's species can't be generated.
+    Entry(EntryError),
+    InvalidLevel {
+        min: u32,
+        max: u32,
+    },
+    /// A table's rates don't add up to 100.
+    InvalidRates {
+        area: String,
+        total: u32,
+    },
+    /// More than one table for the same area, kind of encounter and weather.
+    Duplicate(String),
+}
+
+impl fmt::Display for SlotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SlotError::Json(e) => write!(f, "Malformed slot table dump: {}", e),
+            SlotError::InvalidKind(n) => write!(f, "Invalid encounter kind: {}", n),
+            SlotError::InvalidWeather(n) => write!(f, "Invalid weather: {}", n),
+            SlotError::Entry(e) => write!(f, "{}", e),
+            SlotError::InvalidLevel { min, max } => {
+                write!(f, "Invalid level range: {}-{}", min, max)
+            }
+            SlotError::InvalidRates { area, total } => {
+                write!(f, "Rates for {} add up to {}, not 100", area, total)
+            }
+            SlotError::Duplicate(area) => write!(f, "Duplicate encounter table for {}", area),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SlotError {
+    fn from(e: serde_json::Error) -> Self {
+        SlotError::Json(e)
+    }
+}
+
+impl From<EntryError> for SlotError {
+    fn from(e: EntryError) -> Self {
+        SlotError::Entry(e)
+    }
+}
+
+/// Loads encounter tables from a JSON dump.
+pub fn load_slot_tables(json: &str) -> Result<SlotTables, SlotError> {
+    let dump: SlotDump = serde_json::from_str(json)?;
+    let mut tables: Vec<SlotTable> = Vec::new();
+    for entry in dump.tables.into_iter() {
+        let kind = parse_kind(entry.kind)?;
+        let weather = match entry.weather {
+            Some(n) => Some(parse_weather(n)?),
+            None => None,
+        };
+        let slots = entry
+            .slots
+            .iter()
+            .map(|slot| {
+                validate_slot(slot)?;
+                Ok(EncounterSlot::new(
+                    slot.species,
+                    slot.alt_form,
+                    slot.rate,
+                    Level::new(slot.min_level, slot.max_level),
+                ))
+            })
+            .collect::<Result<Vec<_>, SlotError>>()?;
+
+        let total = slots.iter().map(|slot| slot.rate).sum();
+        if total != 100 {
+            return Err(SlotError::InvalidRates {
+                area: entry.area,
+                total,
+            });
+        }
+        // Tables for every weather would overlap with any other table for the area.
+        let overlaps = tables.iter().any(|t| {
+            t.area == entry.area
+                && t.kind == kind
+                && (t.weather.is_none() || weather.is_none() || t.weather == weather)
+        });
+        if overlaps {
+            return Err(SlotError::Duplicate(entry.area));
+        }
+
+        tables.push(SlotTable::new(entry.area, kind, weather, slots));
+    }
+
+    Ok(SlotTables::new(tables))
+}
+
+fn parse_kind(n: u8) -> Result<SlotKind, SlotError> {
+    match n {
+        0 => Ok(SlotKind::Symbol),
+        1 => Ok(SlotKind::Hidden),
+        2 => Ok(SlotKind::Fishing),
+        _ => Err(SlotError::InvalidKind(n)),
+    }
+}
+
+fn parse_weather(n: u8) -> Result<Weather, SlotError> {
+    match n {
+        0 => Ok(Weather::Clear),
+        1 => Ok(Weather::Overcast),
+        2 => Ok(Weather::Rain),
+        3 => Ok(Weather::Thunderstorm),
+        4 => Ok(Weather::Snow),
+        5 => Ok(Weather::Snowstorm),
+        6 => Ok(Weather::HarshSunlight),
+        7 => Ok(Weather::Sandstorm),
+        8 => Ok(Weather::HeavyFog),
+        _ => Err(SlotError::InvalidWeather(n)),
+    }
+}
+
+/// Checks that a slot's species exists and its level range is valid.
+fn validate_slot(slot: &SlotEntry) -> Result<(), SlotError> {
+    validate_species(slot.species, slot.alt_form)?;
+    if slot.min_level == 0 || slot.min_level > slot.max_level || slot.max_level > 100 {
+        return Err(SlotError::InvalidLevel {
+            min: slot.min_level,
+            max: slot.max_level,
+        });
+    }
+    Ok(())
+}
+
+/// Every area's encounter tables.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SlotTables {
+    tables: Vec<SlotTable>,
+}
+
+#[wasm_bindgen]
+impl SlotTables {
+    /// Returns the names of every area with an encounter table.
+    pub fn get_areas(&self) -> js_sys::Array {
+        let mut areas: Vec<&str> = Vec::new();
+        for table in self.tables.iter() {
+            if !areas.contains(&table.get_area()) {
+                areas.push(table.get_area());
+            }
+        }
+        areas.into_iter().map(JsValue::from).collect()
+    }
+
+    /// Returns the entries of the table used for a given area, kind of encounter and weather.
+    pub fn get_slots(&self, area: &str, kind: SlotKind, weather: Weather) -> js_sys::Array {
+        self.find(area, kind, weather)
+            .map(|table| table.get_slots())
+            .unwrap_or(&[])
+            .iter()
+            .cloned()
+            .map(JsValue::from)
+            .collect()
+    }
+}
+
+impl SlotTables {
+    pub fn new(tables: Vec<SlotTable>) -> Self {
+        SlotTables { tables }
+    }
+
+    /// Returns the table used for a given area, kind of encounter and weather.
+    pub fn find(&self, area: &str, kind: SlotKind, weather: Weather) -> Option<&SlotTable> {
+        self.tables
+            .iter()
+            .find(|table| table.is_used_for(area, kind, weather))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_slot() {
+        let table = SlotTable::new(
+            "Test".to_string(),
+            SlotKind::Symbol,
+            None,
+            vec![
+                EncounterSlot::new(1, 0, 60, Level::new(2, 4)),
+                EncounterSlot::new(4, 0, 30, Level::new(3, 3)),
+                EncounterSlot::new(7, 0, 10, Level::new(5, 6)),
+            ],
+        );
+        assert_eq!(table.get_slot(0).unwrap().species(), 1);
+        assert_eq!(table.get_slot(59).unwrap().species(), 1);
+        assert_eq!(table.get_slot(60).unwrap().species(), 4);
+        assert_eq!(table.get_slot(89).unwrap().species(), 4);
+        assert_eq!(table.get_slot(99).unwrap().species(), 7);
+        assert_eq!(table.get_slot(100), None);
+
+        assert!(table.is_used_for("Test", SlotKind::Symbol, Weather::Rain));
+        assert!(!table.is_used_for("Test", SlotKind::Hidden, Weather::Rain));
+        assert!(!table.is_used_for("Other", SlotKind::Symbol, Weather::Rain));
+    }
+
+    const DUMP: &str = r#"{
+        "Tables": [
+            {
+                "Area": "Test",
+                "Kind": 1,
+                "Slots": [
+                    { "Species": 1, "AltForm": 0, "Rate": 60, "MinLevel": 2, "MaxLevel": 4 },
+                    { "Species": 4, "AltForm": 0, "Rate": 40, "MinLevel": 3, "MaxLevel": 3 }
+                ]
+            },
+            {
+                "Area": "Test",
+                "Kind": 0,
+                "Weather": 2,
+                "Slots": [
+                    { "Species": 7, "AltForm": 0, "Rate": 100, "MinLevel": 5, "MaxLevel": 6 }
+                ]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_load_slot_tables() {
+        let tables = load_slot_tables(DUMP).unwrap();
+
+        // Tables without a weather are used in every weather.
+        let hidden = tables
+            .find("Test", SlotKind::Hidden, Weather::Snow)
+            .unwrap();
+        assert_eq!(hidden.get_slot(59).unwrap().species(), 1);
+        assert_eq!(hidden.get_slot(60).unwrap().get_level(), &Level::new(3, 3));
+
+        let symbol = tables
+            .find("Test", SlotKind::Symbol, Weather::Rain)
+            .unwrap();
+        assert_eq!(symbol.get_slot(99).unwrap().species(), 7);
+        assert_eq!(tables.find("Test", SlotKind::Symbol, Weather::Clear), None);
+        assert_eq!(tables.find("Other", SlotKind::Hidden, Weather::Rain), None);
+    }
+
+    #[test]
+    fn test_load_slot_tables_errors() {
+        let load = |table: &str| load_slot_tables(&format!(r#"{{ "Tables": [{}] }}"#, table));
+        let slot = r#"{ "Species": 1, "AltForm": 0, "Rate": 100, "MinLevel": 2, "MaxLevel": 4 }"#;
+
+        assert!(matches!(load_slot_tables("{}"), Err(SlotError::Json(_))));
+        assert!(matches!(
+            load(&format!(
+                r#"{{ "Area": "A", "Kind": 3, "Slots": [{}] }}"#,
+                slot
+            )),
+            Err(SlotError::InvalidKind(3))
+        ));
+        assert!(matches!(
+            load(&format!(
+                r#"{{ "Area": "A", "Kind": 0, "Weather": 9, "Slots": [{}] }}"#,
+                slot
+            )),
+            Err(SlotError::InvalidWeather(9))
+        ));
+        assert!(matches!(
+            load(
+                r#"{ "Area": "A", "Kind": 0, "Slots": [
+                { "Species": 9999, "AltForm": 0, "Rate": 100, "MinLevel": 2, "MaxLevel": 4 }
+            ] }"#
+            ),
+            Err(SlotError::Entry(EntryError::Species { species: 9999, .. }))
+        ));
+        assert!(matches!(
+            load(
+                r#"{ "Area": "A", "Kind": 0, "Slots": [
+                { "Species": 1, "AltForm": 0, "Rate": 100, "MinLevel": 5, "MaxLevel": 4 }
+            ] }"#
+            ),
+            Err(SlotError::InvalidLevel { min: 5, max: 4 })
+        ));
+        assert!(matches!(
+            load(
+                r#"{ "Area": "A", "Kind": 0, "Slots": [
+                { "Species": 1, "AltForm": 0, "Rate": 90, "MinLevel": 2, "MaxLevel": 4 }
+            ] }"#
+            ),
+            Err(SlotError::InvalidRates { total: 90, .. })
+        ));
+
+        // A table for every weather overlaps with one for a single weather.
+        let table = format!(r#"{{ "Area": "A", "Kind": 0, "Slots": [{}] }}"#, slot);
+        let rain = format!(
+            r#"{{ "Area": "A", "Kind": 0, "Weather": 2, "Slots": [{}] }}"#,
+            slot
+        );
+        assert!(matches!(
+            load(&format!("{}, {}", rain, table)),
+            Err(SlotError::Duplicate(_))
+        ));
+        let snow = rain.replace(r#""Weather": 2"#, r#""Weather": 4"#);
+        assert!(load(&format!("{}, {}", rain, snow)).is_ok());
+    }
+}
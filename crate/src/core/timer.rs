@@ -0,0 +1,91 @@
+//! Timing the wait for a target advance.
+//!
+//! Works like the phase timers used for other RNG manipulations: a fixed first phase gives the
+//! player time to get ready, then a second phase counts down the wait for the target. Missing the
+//! target shows how far off the calibration is, which is folded back into the next attempt.
+use wasm_bindgen::prelude::*;
+
+/// Turns advance gaps into countdown phases.
+#[wasm_bindgen]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct AdvanceTimer {
+    /// Calibrated rate the RNG advances at.
+    advances_per_second: f64,
+    /// Length of the first phase, in milliseconds.
+    pre_timer: f64,
+    /// Correction added to the wait, in milliseconds.
+    calibration: f64,
+}
+
+#[wasm_bindgen]
+impl AdvanceTimer {
+    /// Creates a timer, or returns nothing if the advance rate isn't a positive number.
+    pub fn new(advances_per_second: f64, pre_timer: f64, calibration: f64) -> Option<AdvanceTimer> {
+        if !(advances_per_second > 0.0 && advances_per_second.is_finite()) {
+            return None;
+        }
+        Some(AdvanceTimer {
+            advances_per_second,
+            pre_timer,
+            calibration,
+        })
+    }
+
+    pub fn get_calibration(&self) -> f64 {
+        self.calibration
+    }
+
+    /// Returns the milliseconds to wait for the RNG to move `advances` advances.
+    pub fn get_wait(&self, advances: f64) -> f64 {
+        advances / self.advances_per_second * 1000.0 + self.calibration
+    }
+
+    /// Returns the lengths of each phase, in milliseconds, to go from the current advance to
+    /// the target. The second phase starts once the player starts waiting.
+    pub fn get_phases(&self, current: f64, target: f64) -> Vec<f64> {
+        vec![self.pre_timer, self.get_wait(target - current).max(0.0)]
+    }
+
+    /// Updates the calibration after aiming for `target` and landing on `hit` instead.
+    /// Landing late means the wait was too long, so the calibration is shortened to match.
+    pub fn record_hit(&mut self, target: f64, hit: f64) {
+        self.calibration -= (hit - target) / self.advances_per_second * 1000.0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_rate() {
+        assert_eq!(AdvanceTimer::new(0.0, 5000.0, 0.0), None);
+        assert_eq!(AdvanceTimer::new(-20.0, 5000.0, 0.0), None);
+        assert_eq!(AdvanceTimer::new(f64::NAN, 5000.0, 0.0), None);
+        assert_eq!(AdvanceTimer::new(f64::INFINITY, 5000.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_phases() {
+        let timer = AdvanceTimer::new(20.0, 5000.0, 150.0).unwrap();
+        assert_eq!(timer.get_phases(1000.0, 1400.0), vec![5000.0, 20150.0]);
+        // A target already passed waits for nothing.
+        assert_eq!(timer.get_phases(1400.0, 1000.0), vec![5000.0, 0.0]);
+    }
+
+    #[test]
+    fn test_record_hit() {
+        let mut timer = AdvanceTimer::new(20.0, 5000.0, 0.0).unwrap();
+
+        // Landing 4 advances late means waiting 200ms less next time.
+        timer.record_hit(1400.0, 1404.0);
+        assert_eq!(timer.get_calibration(), -200.0);
+        assert_eq!(timer.get_wait(400.0), 19800.0);
+
+        // Landing early lengthens the wait again, and hitting leaves it alone.
+        timer.record_hit(1400.0, 1398.0);
+        assert_eq!(timer.get_calibration(), -100.0);
+        timer.record_hit(1400.0, 1400.0);
+        assert_eq!(timer.get_calibration(), -100.0);
+    }
+}
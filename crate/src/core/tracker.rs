@@ -0,0 +1,170 @@
+//! Finding the current advance again from a few new blinks.
+//!
+//! Once the state has been recovered, the player only needs to blink a few more times to work out
+//! how far the RNG has moved since, rather than recovering the whole state again.
+use super::overworld::parse_seed_pair;
+use super::rng::Rng;
+use wasm_bindgen::prelude::*;
+
+/// An advance the RNG could be on, with its state.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct TrackedAdvance {
+    /// Advances since the known state.
+    advance: u64,
+    state: (u64, u64),
+}
+
+impl TrackedAdvance {
+    pub fn get_advance(&self) -> u64 {
+        self.advance
+    }
+
+    pub fn get_state(&self) -> (u64, u64) {
+        self.state
+    }
+}
+
+#[wasm_bindgen]
+impl TrackedAdvance {
+    #[wasm_bindgen(getter)]
+    pub fn advance(&self) -> f64 {
+        self.advance as f64
+    }
+
+    /// First half of the state, in hex.
+    #[wasm_bindgen(getter)]
+    pub fn seed_0(&self) -> String {
+        format!("{:016x}", self.state.0)
+    }
+
+    /// Second half of the state, in hex.
+    #[wasm_bindgen(getter)]
+    pub fn seed_1(&self) -> String {
+        format!("{:016x}", self.state.1)
+    }
+}
+
+/// Searches for the advance the RNG is on, from a known earlier state.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AdvanceTracker {
+    state: (u64, u64),
+    /// Each blink, as the advances skipped before it and the bit it revealed.
+    blinks: Vec<(u32, bool)>,
+}
+
+#[wasm_bindgen]
+impl AdvanceTracker {
+    /// Creates a tracker from the two halves of the known state in hex.
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed_0: &str, seed_1: &str) -> Result<AdvanceTracker, JsValue> {
+        Ok(AdvanceTracker::from_state(parse_seed_pair(seed_0, seed_1)?))
+    }
+
+    /// Records a blink on the next advance, with the bit it revealed.
+    pub fn add_blink(&mut self, bit: bool) {
+        self.add_blink_after(0, bit);
+    }
+
+    /// Records a blink after `skipped` advances without one, with the bit it revealed.
+    pub fn add_blink_after(&mut self, skipped: u32, bit: bool) {
+        self.blinks.push((skipped, bit));
+    }
+
+    /// Lists every advance within `window` advances of the known state that the RNG could be on
+    /// now, just after the last blink.
+    pub fn search(&self, window: u32) -> js_sys::Array {
+        self.find_all(window)
+            .into_iter()
+            .map(JsValue::from)
+            .collect()
+    }
+}
+
+impl AdvanceTracker {
+    /// Creates a tracker from the known state.
+    pub fn from_state(state: (u64, u64)) -> Self {
+        AdvanceTracker {
+            state,
+            blinks: Vec::new(),
+        }
+    }
+
+    /// Returns every advance the RNG could be on now, if the blinks started within `window`
+    /// advances of the known state.
+    pub fn find_all(&self, window: u32) -> Vec<TrackedAdvance> {
+        let blink_advances: u64 = self
+            .blinks
+            .iter()
+            .map(|&(skipped, _)| skipped as u64 + 1)
+            .sum();
+
+        let mut rng = Rng::from_state(self.state.0, self.state.1);
+        let mut found = Vec::new();
+        for start in 0..=window as u64 {
+            let (s0, s1) = rng.get_state();
+            let mut candidate = Rng::from_state(s0, s1);
+            let matches = self.blinks.iter().all(|&(skipped, bit)| {
+                for _ in 0..skipped {
+                    candidate.next();
+                }
+                (candidate.next() & 1 == 1) == bit
+            });
+
+            if matches {
+                found.push(TrackedAdvance {
+                    advance: start + blink_advances,
+                    state: candidate.get_state(),
+                });
+            }
+            rng.next();
+        }
+        found
+    }
+
+    /// Returns the advance the RNG is on now, if exactly one fits the blinks.
+    pub fn find(&self, window: u32) -> Option<TrackedAdvance> {
+        match self.find_all(window).as_slice() {
+            [tracked] => Some(*tracked),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const STATE: (u64, u64) = (0x5e5c928d61792fed, 0xed608999e1410aa9);
+
+    #[test]
+    fn test_find() {
+        // Drift 1234 advances, then blink 20 times.
+        let mut rng = Rng::from_state(STATE.0, STATE.1);
+        for _ in 0..1234 {
+            rng.next();
+        }
+        let mut tracker = AdvanceTracker::from_state(STATE);
+        for i in 0..20 {
+            let skipped = i % 3;
+            for _ in 0..skipped {
+                rng.next();
+            }
+            tracker.add_blink_after(skipped, rng.next() & 1 == 1);
+        }
+
+        let tracked = tracker.find(5000).unwrap();
+        assert_eq!(tracked.get_advance(), 1234 + 20 + 19);
+        assert_eq!(tracked.get_state(), rng.get_state());
+
+        // Too narrow a window misses it.
+        assert_eq!(tracker.find(1000), None);
+
+        // A single blink fits about half of all advances.
+        let mut vague = AdvanceTracker::from_state(STATE);
+        vague.add_blink(true);
+        assert!(vague.find_all(100).len() > 1);
+        assert_eq!(vague.find(100), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Trainer IDs and shiny checks against them.
+use super::mon::Shininess;
+use wasm_bindgen::prelude::*;
+
+/// A trainer's IDs, shared by overworld and raid generation.
+///
+/// The game stores a single 32-bit ID, with the SID in the upper 16 bits and the TID in the
+/// lower 16 bits. Gen 7+ displays the same ID split into a six-digit TID7 and the remaining SID7.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Trainer {
+    tid: u16,
+    sid: u16,
+    /// TID XOR SID, the full 16 bits. A PID is shiny when its halves XOR this to below 16.
+    /// The TSV shown by most tools is this shifted right by 4.
+    shiny_xor_base: u16,
+}
+
+#[wasm_bindgen]
+impl Trainer {
+    /// Creates a trainer from the 16-bit TID and SID.
+    #[wasm_bindgen(constructor)]
+    pub fn new(tid: u16, sid: u16) -> Self {
+        Trainer {
+            tid,
+            sid,
+            shiny_xor_base: tid ^ sid,
+        }
+    }
+
+    /// Creates a trainer from the displayed six-digit TID7 and SID7.
+    /// Returns None if they don't form a valid 32-bit ID.
+    pub fn from_tid7(tid7: u32, sid7: u32) -> Option<Trainer> {
+        if tid7 >= 1_000_000 {
+            return None;
+        }
+        let id = (sid7 as u64) * 1_000_000 + tid7 as u64;
+        if id > u32::MAX as u64 {
+            return None;
+        }
+        Some(Trainer::new(id as u16, (id >> 16) as u16))
+    }
+
+    pub fn get_tid(&self) -> u16 {
+        self.tid
+    }
+
+    pub fn get_sid(&self) -> u16 {
+        self.sid
+    }
+
+    /// Returns the displayed six-digit TID.
+    pub fn get_tid7(&self) -> u32 {
+        self.get_tidsid() % 1_000_000
+    }
+
+    /// Returns the displayed SID that goes with the six-digit TID.
+    pub fn get_sid7(&self) -> u32 {
+        self.get_tidsid() / 1_000_000
+    }
+
+    /// Returns the full 32-bit ID, laid out as the game stores it.
+    pub fn get_tidsid(&self) -> u32 {
+        ((self.sid as u32) << 16) | self.tid as u32
+    }
+
+    /// Returns TID XOR SID, which a shiny PID's halves XOR to within 16 of.
+    pub fn get_shiny_xor_base(&self) -> u16 {
+        self.shiny_xor_base
+    }
+
+    /// Returns the XOR of the PID's halves with the TID and SID. Below 16 is shiny.
+    pub fn get_shiny_xor(&self, pid: u32) -> u16 {
+        (pid >> 16) as u16 ^ pid as u16 ^ self.shiny_xor_base
+    }
+
+    /// Determines the shiny type of a PID for this trainer.
+    pub fn get_shininess(&self, pid: u32) -> Shininess {
+        match self.get_shiny_xor(pid) {
+            0 => Shininess::Square,
+            xor if xor < 16 => Shininess::Star,
+            _ => Shininess::None,
+        }
+    }
+
+    pub fn is_shiny(&self, pid: u32) -> bool {
+        self.get_shiny_xor(pid) < 16
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tid7() {
+        let trainer = Trainer::new(57649, 60914);
+        assert_eq!(trainer.get_tidsid(), 3992117553);
+        assert_eq!(trainer.get_tid7(), 117553);
+        assert_eq!(trainer.get_sid7(), 3992);
+        assert_eq!(Trainer::from_tid7(117553, 3992), Some(trainer));
+
+        assert_eq!(Trainer::from_tid7(0, 0), Some(Trainer::new(0, 0)));
+        assert_eq!(Trainer::from_tid7(1_000_000, 0), None);
+        assert_eq!(
+            Trainer::from_tid7(967295, 4294),
+            Some(Trainer::new(0xffff, 0xffff))
+        );
+        assert_eq!(Trainer::from_tid7(967296, 4294), None);
+    }
+
+    #[test]
+    fn test_shininess() {
+        let trainer = Trainer::new(57649, 60914);
+        assert_eq!(trainer.get_shiny_xor_base(), 57649 ^ 60914);
+
+        let lsbs = 0x1234;
+        let base = trainer.get_shiny_xor_base() as u32;
+        assert_eq!(
+            trainer.get_shininess(((lsbs ^ base) << 16) | lsbs),
+            Shininess::Square
+        );
+        assert_eq!(
+            trainer.get_shininess(((lsbs ^ base ^ 15) << 16) | lsbs),
+            Shininess::Star
+        );
+        assert_eq!(
+            trainer.get_shininess(((lsbs ^ base ^ 16) << 16) | lsbs),
+            Shininess::None
+        );
+        assert!(trainer.is_shiny(((lsbs ^ base ^ 1) << 16) | lsbs));
+    }
+
+    #[test]
+    fn test_id_layout() {
+        // The trainer from the overworld fixtures. The SID is the upper half of the stored ID,
+        // so swapping the halves gives a different ID and TID7/SID7, though the same shiny XOR base.
+        let trainer = Trainer::new(57649, 60914);
+        let swapped = Trainer::new(60914, 57649);
+        assert_eq!(trainer.get_tidsid(), 0xedf2_e131);
+        assert_eq!(swapped.get_tidsid(), 0xe131_edf2);
+        assert_eq!((swapped.get_tid7(), swapped.get_sid7()), (145778, 3778));
+        assert_eq!(Trainer::from_tid7(117553, 3992).unwrap().get_tid(), 57649);
+        assert_eq!(Trainer::from_tid7(117553, 3992).unwrap().get_sid(), 60914);
+        assert_eq!(swapped.get_shiny_xor_base(), trainer.get_shiny_xor_base());
+    }
+
+    #[test]
+    fn test_fixture_pids() {
+        // PIDs from the static encounter fixtures, none of which are shiny for their trainer.
+        let trainer = Trainer::new(57649, 60914);
+        let fixtures = [
+            (0xc9d20df8, 51433),
+            (0x6b3179c9, 7739),
+            (0x09d6c7d1, 49860),
+            (0x06c0dd0f, 55052),
+            (0x38f83bce, 4085),
+            (0x1d004a75, 23478),
+        ];
+        for &(pid, xor) in fixtures.iter() {
+            assert_eq!(trainer.get_shiny_xor(pid), xor);
+            assert_eq!(trainer.get_shininess(pid), Shininess::None);
+        }
+    }
+}
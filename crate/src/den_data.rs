@@ -0,0 +1,6156 @@
+//! Den encounter tables accessible from JavaScript.
+use super::core::den::{Den, DenEncounter, DenTable, GameVersion};
+use lazy_static::lazy_static;
+use std::iter::FromIterator;
+use wasm_bindgen::prelude::*;
+
+/// Returns the number of dens.
+#[wasm_bindgen]
+pub fn get_den_count() -> usize {
+    DEN_DATA.get_den_count()
+}
+
+/// Returns the encounter table for a given den and game.
+#[wasm_bindgen]
+pub fn get_den_encounters(den: usize, version: GameVersion) -> js_sys::Array {
+    let encounters = DEN_DATA
+        .get_den(den)
+        .map(|den| den.get_encounters(version))
+        .unwrap_or(&[])
+        .iter()
+        .cloned()
+        .map(JsValue::from);
+
+    js_sys::Array::from_iter(encounters)
+}
+
+/// Returns a single encounter from a given den and game.
+#[wasm_bindgen]
+pub fn get_den_encounter(den: usize, version: GameVersion, entry: usize) -> Option<DenEncounter> {
+    DEN_DATA
+        .get_den(den)
+        .and_then(|den| den.get_encounters(version).get(entry))
+        .cloned()
+}
+
+/// Returns the den number for a given nest hash.
+#[wasm_bindgen]
+pub fn get_den_by_hash(hash: u64) -> Option<usize> {
+    DEN_DATA.get_den_by_hash(hash)
+}
+
+// The nest hashes are the den ids from the JavaScript tables, which were rounded to doubles
+// there. They still need replacing with the exact hashes from a dump of the game's nest data;
+// until then, lookups only match the rounded values.
+lazy_static! {
+    pub static ref DEN_DATA: DenTable = DenTable::new(vec![
+        Den::new(0, vec![], vec![],),
+        Den::new(
+            1676046420423019000,
+            vec![
+                DenEncounter::new(236, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(66, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(532, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(559, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(67, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(533, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(106, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(107, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(560, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(534, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(68, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(237, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(236, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(66, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(532, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(453, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(533, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(67, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(107, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(106, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(454, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(237, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(68, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(534, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676045320911390700,
+            vec![
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(517, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(677, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(574, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(605, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(281, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(678, 0, 3, 3, 1, false, [false, false, true, true, false]),
+                DenEncounter::new(575, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(518, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(576, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(338, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(517, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(677, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(577, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(605, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(281, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(678, 1, 3, 3, 2, false, [false, false, true, true, false]),
+                DenEncounter::new(578, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(518, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(579, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(337, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676044221399762700,
+            vec![
+                DenEncounter::new(438, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(111, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(525, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(689, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(112, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(185, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(526, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(213, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(438, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(111, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(525, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(689, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(112, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(185, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(526, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(213, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676051917981160000,
+            vec![
+                DenEncounter::new(10, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(736, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(290, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(11, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(632, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(737, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(291, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(12, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(738, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(632, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(10, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(736, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(290, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(632, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(11, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(737, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(291, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(12, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(738, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(632, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676050818469532000,
+            vec![
+                DenEncounter::new(10, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(415, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(742, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(11, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(596, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(12, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(743, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(10, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(415, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(742, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(11, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(596, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(12, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(743, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676049718957903600,
+            vec![
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(355, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(425, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(708, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(592, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(710, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(93, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(356, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(426, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(709, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(593, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(355, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(425, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(708, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(592, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(710, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(93, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(356, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(426, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(709, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(593, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676048619446275300,
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(458, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(223, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(320, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(224, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(226, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(171, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(746, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(458, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(223, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(320, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(224, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(226, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(171, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(746, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676056316027672800,
+            vec![
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(422, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(320, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(746, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(834, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(847, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(422, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(320, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(746, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(834, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(847, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676055216516044800,
+            vec![
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(535, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(90, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(834, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(195, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(342, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(535, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(90, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(834, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(195, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(342, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675055760446190000,
+            vec![
+                DenEncounter::new(236, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(759, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(852, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(674, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(83, 1, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(539, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(760, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(701, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(865, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(853, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(870, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(236, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(759, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(852, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(674, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(759, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(538, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(760, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(701, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(760, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(853, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(870, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675056859957818400,
+            vec![
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(52, 2, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(878, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(600, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(863, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(601, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(52, 2, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(878, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(600, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(863, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(601, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675057959469446700,
+            vec![
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(599, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(436, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(208, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(303, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(777, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(599, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(436, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(208, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(777, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675059058981074700,
+            vec![
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(825, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(857, 0, 2, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(561, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(178, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 0, 4, 3, 1, false, [false, false, true, true, true]),
+                DenEncounter::new(561, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(177, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(77, 1, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(857, 0, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(561, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(178, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 1, 4, 3, 2, false, [false, false, true, true, true]),
+                DenEncounter::new(765, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(78, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675060158492703000,
+            vec![
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(360, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(343, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(122, 1, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(561, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(178, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 0, 4, 3, 1, false, [false, false, true, true, true]),
+                DenEncounter::new(344, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(202, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(360, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(343, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(122, 1, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(561, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(178, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 1, 4, 3, 2, false, [false, false, true, true, true]),
+                DenEncounter::new(344, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(202, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675061258004331300,
+            vec![
+                DenEncounter::new(837, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(525, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(558, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(689, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(95, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(526, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(837, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(525, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(558, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(689, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(839, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(526, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(95, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675062357515959300,
+            vec![
+                DenEncounter::new(50, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(749, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(290, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(529, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(95, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(339, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(208, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(340, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(660, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(51, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(530, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(750, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(50, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(749, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(290, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(529, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(95, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(339, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(208, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(340, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(660, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(51, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(530, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(750, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675063457027587600,
+            vec![
+                DenEncounter::new(843, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(449, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(220, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(328, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(221, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(329, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(618, 1, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(867, 0, 4, 3, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(450, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(330, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(844, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(843, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(449, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(328, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(220, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(221, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(618, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(329, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(867, 0, 4, 3, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(330, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(450, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(844, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675064556539216000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(554, 1, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(758, 0, 2, 3, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(38, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(631, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(555, 2, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(631, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(608, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(758, 0, 3, 3, 2, false, [false, false, true, true, false]),
+                DenEncounter::new(59, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(631, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1675065656050844000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(758, 0, 2, 3, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(38, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(776, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(608, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(758, 0, 3, 3, 2, false, [false, false, true, true, false]),
+                DenEncounter::new(59, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(758, 0, 4, 3, 2, false, [false, false, true, true, true]),
+                DenEncounter::new(324, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677890301423150300,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(554, 1, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(758, 0, 2, 3, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(838, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(38, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(555, 2, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(324, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(758, 0, 3, 3, 2, false, [false, false, true, true, false]),
+                DenEncounter::new(59, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677889201911522300,
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(220, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(459, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(225, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(583, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(221, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(713, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(460, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(220, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(459, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(225, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(583, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(221, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(713, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(460, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677892500446407000,
+            vec![
+                DenEncounter::new(220, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(613, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(872, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(221, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(91, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(614, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(866, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(473, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(461, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(220, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(613, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(872, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(221, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(91, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(614, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(866, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(473, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(461, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677891400934778600,
+            vec![
+                DenEncounter::new(361, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(872, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(554, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(122, 1, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(459, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(460, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(362, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(866, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(478, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(555, 2, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(361, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(872, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(225, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(122, 1, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(459, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(460, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(362, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(866, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(478, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(875, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677894699469663200,
+            vec![
+                DenEncounter::new(172, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(309, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(737, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(25, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(25, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(310, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(171, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(738, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(26, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(172, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(309, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(737, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(25, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(25, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(310, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(171, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(738, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(26, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677893599958035000,
+            vec![
+                DenEncounter::new(835, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(694, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(25, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(171, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(836, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(695, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(777, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(877, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(835, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(694, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(25, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(171, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(836, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(695, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(777, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(877, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677896898492919600,
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(273, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(761, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(43, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(274, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(44, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(762, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(275, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(763, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(45, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(182, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(270, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(761, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(43, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(271, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(44, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(762, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(272, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(763, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(45, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(182, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677895798981291500,
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(546, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(840, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(420, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(597, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(421, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(841, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(546, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(840, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(420, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(597, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(421, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(842, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677881505330124800,
+            vec![
+                DenEncounter::new(710, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(708, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(710, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(755, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(710, 2, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(756, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(556, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(709, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(711, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(781, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(710, 3, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(710, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(708, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(710, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(755, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(710, 2, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(756, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(556, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(709, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(711, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(781, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(710, 3, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1677880405818496500,
+            vec![
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(568, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(43, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(452, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(45, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(748, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(569, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(435, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(568, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(43, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 1, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(315, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(452, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(45, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(435, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(569, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(748, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676898541934693400,
+            vec![
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(43, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(44, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(93, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(109, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(211, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(45, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(315, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(849, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(110, 1, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(43, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(44, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(93, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(109, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(211, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(45, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(315, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(849, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(110, 1, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676899641446321400,
+            vec![
+                DenEncounter::new(519, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(627, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(520, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(521, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(164, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(528, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(628, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(561, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(519, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(629, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(520, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(521, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(164, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(528, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(630, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(561, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676896342911436800,
+            vec![
+                DenEncounter::new(821, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(278, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(425, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(822, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(426, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(279, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(845, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(821, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(278, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(425, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(822, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(426, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(279, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(845, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676897442423065000,
+            vec![
+                DenEncounter::new(173, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(742, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(684, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(35, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(755, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(176, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(36, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(743, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(756, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(685, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(173, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(742, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(682, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(35, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(755, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(176, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(36, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(743, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(756, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(683, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676894143888180500,
+            vec![
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(868, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(35, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(281, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(36, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(869, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(303, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(868, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(35, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(281, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(36, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(869, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(78, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676895243399808800,
+            vec![
+                DenEncounter::new(509, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(435, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(461, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(687, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(342, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(275, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(509, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(435, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(461, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(687, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(342, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(302, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676891944864924200,
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(509, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(633, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(828, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(860, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(861, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(634, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(635, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(509, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(629, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(828, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(860, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(861, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(630, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(248, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676893044376552200,
+            vec![
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(328, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(610, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(782, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(329, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(783, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(611, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(612, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(330, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(776, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(784, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(610, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(328, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(704, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(329, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(611, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(705, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(330, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(612, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(780, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(706, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676907338027719000,
+            vec![
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(840, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(782, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(714, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(886, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(715, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(783, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(784, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(841, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(887, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(840, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(704, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(714, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(886, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(715, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(705, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(706, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(842, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(887, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1676908437539347200,
+            vec![
+                DenEncounter::new(659, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(519, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(572, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(694, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(759, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(660, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(164, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(521, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(695, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(573, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(760, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(659, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(519, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(572, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(694, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(759, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(660, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(164, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(521, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(695, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(573, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(760, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1679873820400064500,
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(831, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(446, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(876, 0, 2, 3, 1, false, [false, true, false, false, false]),
+                DenEncounter::new(820, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(820, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(832, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(660, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(628, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(831, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(446, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(876, 1, 2, 3, 2, false, [false, true, false, false, false]),
+                DenEncounter::new(820, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(820, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(832, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(660, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(765, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1679872720888436500,
+            vec![
+                DenEncounter::new(535, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(90, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(846, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(91, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(171, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(746, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(748, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(535, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(90, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(536, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(846, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(748, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(91, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(171, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(746, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(847, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1679871621376808200,
+            vec![
+                DenEncounter::new(422, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(771, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(99, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(342, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(689, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(593, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(834, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(422, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 2, 3, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(771, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(99, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(342, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(689, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(593, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(834, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438842885794419000,
+            vec![
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(355, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(93, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(710, 0, 2, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(356, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(867, 0, 4, 3, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(855, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(477, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(94, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(355, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(93, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(222, 1, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(356, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 4, 3, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(867, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(864, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(477, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(94, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438843985306049000,
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(349, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(350, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(349, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(350, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438845084817676000,
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(95, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(632, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(600, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(601, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(448, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(95, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(600, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(632, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(601, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(448, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438837388236278000,
+            vec![
+                DenEncounter::new(767, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(588, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(616, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(557, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(768, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(589, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(292, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(767, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(616, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(588, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(557, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(768, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(617, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(292, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438838487747908000,
+            vec![
+                DenEncounter::new(679, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(425, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(680, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(710, 0, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(426, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(711, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(855, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(867, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(681, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(679, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(425, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(680, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(222, 1, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(426, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(855, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(864, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(867, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(681, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438839587259535000,
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(66, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(559, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(759, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(760, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(870, 0, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(67, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(560, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(68, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(766, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(448, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(475, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(66, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(453, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(759, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(760, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(870, 0, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(67, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(454, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(68, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(448, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(475, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438840686771163000,
+            vec![
+                DenEncounter::new(52, 2, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(679, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(437, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(863, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(618, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(884, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(52, 2, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(679, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(437, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(863, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(618, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(884, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438832990189767000,
+            vec![
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(857, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(281, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(528, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(857, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(281, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(528, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13438834089701394000,
+            vec![
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(438, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(837, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(185, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(689, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(95, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(874, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(438, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(837, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(246, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(247, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(95, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(248, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439833545771248000,
+            vec![
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(339, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(195, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(618, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(867, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(339, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(195, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(618, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(867, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439832446259620000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(4, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(5, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(38, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(38, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(6, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(4, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(5, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(6, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439831346747992000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(38, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(776, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439830247236364000,
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(554, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(225, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(362, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(555, 2, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(613, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(225, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(362, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(875, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439829147724737000,
+            vec![
+                DenEncounter::new(835, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(25, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(171, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(836, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(777, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(877, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(835, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(25, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(171, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(836, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(777, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(877, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439828048213107000,
+            vec![
+                DenEncounter::new(172, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(309, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(694, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(25, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(25, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(479, 5, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(479, 4, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(479, 3, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(479, 2, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(479, 1, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(172, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(309, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(694, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(25, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(25, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(479, 5, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(479, 4, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(479, 3, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(479, 2, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(479, 1, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439826948701479000,
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(273, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(274, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(315, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(275, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(407, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(841, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(270, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(271, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(315, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(272, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(407, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(842, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439825849189851000,
+            vec![
+                DenEncounter::new(420, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(273, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(546, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(274, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(755, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(421, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(756, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(275, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(781, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(420, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(270, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(546, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(271, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(755, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(421, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(756, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(272, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(781, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439824749678223000,
+            vec![
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(568, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(109, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(848, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(569, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(452, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(435, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(748, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(568, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(109, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(848, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(569, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(452, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(435, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13439823650166596000,
+            vec![
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(821, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(278, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(12, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(822, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(164, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(279, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(225, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(821, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(278, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(12, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(822, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(164, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(279, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(225, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440790120887603000,
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(755, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(756, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(303, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(778, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(755, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(756, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(78, 1, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(778, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440791220399230000,
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(559, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(828, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(461, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(560, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(635, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(629, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(828, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(461, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(630, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(248, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440787921864346000,
+            vec![
+                DenEncounter::new(328, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(840, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(610, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(782, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(611, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(783, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(776, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(784, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(886, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(612, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(887, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(610, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(840, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(328, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(704, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(329, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(705, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(780, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(706, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(886, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(330, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(887, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440789021375975000,
+            vec![
+                DenEncounter::new(659, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(519, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(133, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(520, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(831, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(521, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(832, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(628, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(876, 0, 4, 4, 1, false, [false, false, false, true, true]),
+                DenEncounter::new(133, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(659, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(519, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(133, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(520, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(831, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(521, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(832, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(765, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(876, 1, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(133, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440794518934116000,
+            vec![
+                DenEncounter::new(132, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(132, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440795618445744000,
+            vec![
+                DenEncounter::new(458, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(226, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(134, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(458, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(226, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(134, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440792319910859000,
+            vec![
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(857, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(437, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(528, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(196, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(857, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(437, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(528, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(196, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440793419422489000,
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(828, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(197, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(686, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(828, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(197, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440798916980630000,
+            vec![
+                DenEncounter::new(420, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(761, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(546, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(762, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(597, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(421, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(763, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(470, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(420, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(761, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(546, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(762, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(597, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(421, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(763, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(470, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13440800016492257000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(38, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(136, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(38, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(136, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13441641142887650000,
+            vec![
+                DenEncounter::new(835, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(25, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(694, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(171, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(836, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(695, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(738, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(25, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(135, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(835, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(848, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(25, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(694, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(170, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(171, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(836, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(695, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(738, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(25, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(135, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13441640043376022000,
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(872, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(583, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(873, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(478, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(471, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(872, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(583, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(873, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(478, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(471, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13441643341910905000,
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(684, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(685, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(868, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(700, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(682, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(683, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(868, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(700, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13441642242399277000,
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(339, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(746, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(99, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(340, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(211, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(195, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(339, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(746, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(99, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(340, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(211, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(195, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13441636744841136000,
+            vec![
+                DenEncounter::new(458, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(223, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(320, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(771, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(99, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(550, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(211, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(224, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(226, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(458, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(223, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(320, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(771, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(99, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(550, 1, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(211, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(224, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(226, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972150845118706000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(4, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(5, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(38, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(38, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(6, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(4, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(5, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(6, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972151944630334000,
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(771, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(99, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(746, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(834, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(771, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(99, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(746, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(834, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972153044141962000,
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(273, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(274, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(315, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(275, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(407, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(841, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(406, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(270, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(271, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(315, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(272, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(407, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(842, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972145347560565000,
+            vec![
+                DenEncounter::new(37, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(38, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(58, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(607, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(59, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972146447072194000,
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(66, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(759, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(83, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(760, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(67, 0, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(870, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(701, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(448, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(475, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(865, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(68, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(679, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(92, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(680, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(222, 1, 3, 3, 0, false, [false, true, true, true, false]),
+                DenEncounter::new(93, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(855, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(864, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(867, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(94, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972147546583821000,
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(755, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(756, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(303, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(869, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(77, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(756, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(78, 1, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(869, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972148646095450000,
+            vec![
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(438, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(837, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(185, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(689, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(95, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(874, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(582, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(613, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(122, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(712, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(225, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(362, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(875, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972140949514053000,
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(95, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(632, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(600, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(601, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(884, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(447, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(599, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(95, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(600, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(632, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(437, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(208, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(601, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(884, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4972142049025680000,
+            vec![
+                DenEncounter::new(52, 2, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(679, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(437, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(863, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(618, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(884, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(52, 2, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(436, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(597, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(679, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(437, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(863, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(598, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(618, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(884, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4973141505095535000,
+            vec![
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(568, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(109, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(848, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(452, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(435, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(748, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(569, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(434, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(568, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(109, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(848, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(452, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(849, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(435, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 2, false, [false, false, false, false, true]),
+                DenEncounter::new(569, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4973140405583907000,
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(684, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(685, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(868, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(175, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(682, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(176, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(683, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(868, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(468, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4973139306072279000,
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(559, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(828, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(560, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(635, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(827, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(263, 1, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(629, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(510, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(264, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(828, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(675, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(630, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(248, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(861, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4973138206560650000,
+            vec![
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(821, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(278, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(12, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(822, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(164, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(279, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(561, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(177, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(163, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(821, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(278, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(12, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(822, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(164, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(279, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(178, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(701, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(561, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4973137107049022000,
+            vec![
+                DenEncounter::new(767, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(588, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(616, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(557, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(768, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(589, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(12, 0, 4, 4, 0, true, [true, true, true, true, true]),
+            ],
+            vec![
+                DenEncounter::new(767, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(616, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(588, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(557, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(768, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(617, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(12, 0, 4, 4, 0, true, [true, true, true, true, true]),
+            ],
+        ),
+        Den::new(
+            4973136007537394000,
+            vec![
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(99, 0, 4, 4, 0, true, [false, true, true, true, true]),
+            ],
+            vec![
+                DenEncounter::new(341, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(846, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(771, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(99, 0, 4, 4, 0, true, [false, true, true, true, true]),
+            ],
+        ),
+        Den::new(
+            4973134908025766000,
+            vec![
+                DenEncounter::new(767, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(588, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(616, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(557, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(768, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(589, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, true, [false, false, true, true, true]),
+            ],
+            vec![
+                DenEncounter::new(767, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(824, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(616, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(588, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(557, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(768, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(617, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, true, [false, false, true, true, true]),
+            ],
+        ),
+        Den::new(
+            4973133808514137000,
+            vec![
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(339, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(195, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(618, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(844, 0, 4, 4, 0, true, [false, false, false, true, true]),
+            ],
+            vec![
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(339, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(562, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(536, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(195, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(618, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(423, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(844, 0, 4, 4, 0, true, [false, false, false, true, true]),
+            ],
+        ),
+        Den::new(
+            7556351317940148000,
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+            ],
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+            ],
+        ),
+        Den::new(
+            7556352417451777000,
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+            ],
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+            ],
+        ),
+        Den::new(
+            7556353516963405000,
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+            ],
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [true, true, true, false, true]),
+            ],
+        ),
+        Den::new(
+            7556345820382007000,
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, true, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, false, false, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            2498979820391853600,
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(819, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(819, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(819, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(819, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(819, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            2447364886159769000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(174, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(506, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(427, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(507, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(428, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(428, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(40, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(206, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(508, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(174, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(506, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(427, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(507, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(428, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(428, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(40, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(206, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(508, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            15632276665898510000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(506, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(759, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(39, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(427, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(206, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(832, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(428, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(508, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(760, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(40, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(506, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(759, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(39, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(427, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(206, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(832, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(428, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(508, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(760, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(40, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            2447363786648141000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(293, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(108, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(241, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(294, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(294, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(108, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(241, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(626, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(128, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(295, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(463, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(293, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(108, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(241, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(294, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(294, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(108, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(241, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(626, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(128, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(295, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(463, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            15632275566386880000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(293, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(128, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(108, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(241, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(241, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(626, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(128, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(295, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(573, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(295, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(463, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(293, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(128, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(108, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(241, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(241, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(626, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(128, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(295, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(573, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(295, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(463, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            6984833918694526000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(27, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(551, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(104, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(27, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(552, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(28, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(844, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(28, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(105, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(115, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(27, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(551, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(104, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(27, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(552, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(28, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(844, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(28, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(105, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(115, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            14413583907274220000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(27, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(104, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(328, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(328, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(552, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(28, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(105, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(115, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(330, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(623, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(27, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(104, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(328, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(328, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(552, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(28, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(105, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(115, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(330, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(623, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7956530560371257000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(702, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(81, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(403, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(877, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(702, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(404, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(702, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(82, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(405, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(462, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(702, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(81, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(403, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(877, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(702, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(404, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(702, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(82, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(405, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(462, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            2024757571205803800,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(403, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(172, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(25, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(871, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(404, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(26, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(836, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(702, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(310, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(405, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(462, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(403, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(172, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(25, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(871, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(404, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(26, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(836, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(702, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(310, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(405, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(462, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            11635283243122928000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(661, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(627, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(587, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(662, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(662, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(628, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(587, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(528, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(628, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(663, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(661, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(629, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(587, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(662, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(662, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(630, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(587, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(528, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(630, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(663, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            17629394089387610000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(163, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(519, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(627, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(520, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(528, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(164, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(521, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(663, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(587, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(628, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(663, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(163, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(519, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(629, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(520, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(528, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(164, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(521, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(663, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(587, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(630, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(663, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7854659797556875000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(127, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(825, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(127, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(127, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(558, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(123, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(123, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(127, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(212, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(214, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(557, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(825, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(214, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(214, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(558, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(123, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(123, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(214, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(826, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(212, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5999950843982639000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(123, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(127, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(213, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(544, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(123, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(558, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(545, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(617, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(589, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(127, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(212, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(123, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(214, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(213, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(544, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(123, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(558, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(545, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(617, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(589, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(214, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(212, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            2997411918588892000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(590, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(753, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(548, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(754, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(591, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(114, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(549, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(754, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(549, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(591, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(465, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(590, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(753, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(548, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(754, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(591, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(114, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(549, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(754, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(549, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(591, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(465, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            12562706121429926000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(114, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(753, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(590, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(754, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(556, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(549, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(754, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(591, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(465, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(549, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(460, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(114, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(753, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(590, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(754, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(556, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(549, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(754, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(591, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(465, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(549, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(460, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            6589539950519384000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(661, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(636, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(757, 0, 2, 3, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(662, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(636, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(663, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(324, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(637, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(661, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(757, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(636, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(757, 0, 2, 3, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(662, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(636, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(324, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(663, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(758, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(324, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(637, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            3561902408726248000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(636, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(607, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(636, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(757, 0, 2, 2, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(324, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(758, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(663, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(637, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(38, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(6, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(636, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(607, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(636, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(757, 0, 2, 2, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(324, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(758, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(663, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(637, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(59, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(6, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            8769170721942625000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(111, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(525, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(112, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(558, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(112, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(526, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(524, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(111, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(525, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(112, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(558, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(112, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(526, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(558, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            14477537978666912000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(744, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(438, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(111, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(112, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(213, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(745, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(185, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(526, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(558, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(744, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(438, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(111, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(112, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(213, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(745, 1, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(185, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(526, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(558, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(464, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13305292637317526000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(102, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(63, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(64, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(281, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(64, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(103, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(103, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(65, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(121, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(102, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(63, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(280, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(64, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(281, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(765, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(103, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(103, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(65, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(121, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            16069264858016262000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(605, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(63, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(79, 1, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(605, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(64, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(79, 1, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(518, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(876, 0, 4, 2, 1, false, [false, false, false, true, true]),
+                DenEncounter::new(606, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(65, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(678, 0, 4, 2, 1, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(605, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(63, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(79, 1, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(605, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(765, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(79, 1, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(518, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(876, 1, 4, 2, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(606, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(65, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(678, 1, 4, 2, 2, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            6672704941776910000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(543, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(72, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(544, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(544, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(452, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(452, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(545, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(543, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(451, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(72, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(544, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(544, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(452, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(452, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(545, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            17951961757311600000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(747, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(211, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(544, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(544, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(591, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(545, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(452, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(545, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(747, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(211, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(544, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(544, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(211, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(591, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(748, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(545, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(452, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(545, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            14284833672245135000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(318, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(318, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(570, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(319, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(687, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(452, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(319, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(571, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(318, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(318, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(570, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(319, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(687, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(452, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(687, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(319, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(571, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7704513452465554000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(570, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(318, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(570, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(686, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(552, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(687, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(828, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(571, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(319, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(510, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(570, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(318, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(570, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(686, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(552, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(687, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(828, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(571, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(319, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(510, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13563999851587424000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(619, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(852, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(619, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(559, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(766, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(560, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(620, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(620, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(560, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(853, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(620, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(619, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(852, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(619, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(453, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(853, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(454, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(620, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(620, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(454, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(853, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(620, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            502513031628181000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(619, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(559, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(539, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(620, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(870, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(766, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(539, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(620, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(560, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(853, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(865, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(619, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(453, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(538, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(620, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(870, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(620, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(538, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(620, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(454, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(853, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(870, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            16341001078884807000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(174, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(298, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(764, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(183, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(764, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(707, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(184, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(40, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(764, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(174, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(298, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(764, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(39, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(183, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(764, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(707, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(184, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(40, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(282, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(764, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            9913932150092392000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(173, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(755, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(183, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(35, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(281, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(707, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(764, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(36, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(282, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(756, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(184, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(173, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(755, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(183, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(35, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(281, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(707, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(764, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(36, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(282, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(756, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(184, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            342604449375897800,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(769, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(592, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(104, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(425, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(593, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(426, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(770, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(593, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(426, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(105, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(770, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(769, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(592, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(104, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(425, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(593, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(426, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(770, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(593, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(426, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(105, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(770, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            8253110425161551000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(769, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(592, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(769, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(425, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(593, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(426, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(711, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 1, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 2, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 3, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(770, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(769, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(592, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(769, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(425, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(593, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(426, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(711, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 1, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 2, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(711, 3, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(864, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5830741396702654000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(707, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(81, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(81, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(227, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(82, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(82, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(707, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(462, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(227, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(707, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(81, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(624, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(81, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(227, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(82, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(82, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(707, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(625, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(462, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(227, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            17953607996949684000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(81, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(227, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(436, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(52, 2, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(82, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(601, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(227, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(437, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(863, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(448, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(625, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(81, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(227, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(436, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(52, 2, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(82, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(601, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(227, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(437, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(863, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(448, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(625, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            12738905581603037000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(782, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(116, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(117, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(621, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(783, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(621, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(841, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(230, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(784, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(704, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(116, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(840, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(117, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(621, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(705, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(621, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(842, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(230, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(706, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4426791916416849000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(116, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(621, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(776, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(782, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(117, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(783, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(776, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(621, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(715, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(230, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(784, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(116, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(621, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(780, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(704, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(117, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(705, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(780, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(621, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(715, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(230, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(706, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4780541378243794000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(60, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(118, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(61, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(61, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(119, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(342, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(195, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(62, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(186, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(60, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(194, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(118, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(61, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(61, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(119, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(342, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(195, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(62, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(186, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            18345017229883238000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(341, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(118, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(61, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(195, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(119, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(62, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(342, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(752, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(186, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(341, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(751, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(118, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(61, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(342, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(195, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(119, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(62, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(342, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(752, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(186, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4780540278732166000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(54, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(846, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(339, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(55, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(845, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(55, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(834, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(340, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(55, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(54, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(833, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(846, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(339, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(55, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(845, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(55, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(834, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(340, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(55, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            18345016130371610000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(846, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(535, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(54, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(536, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(55, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(340, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(55, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(550, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(846, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(535, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(54, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(536, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(55, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(340, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(55, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(537, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(550, 1, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4780539179220538000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(72, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(223, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(746, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(224, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(226, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(99, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(72, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(98, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(223, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(746, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(224, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(226, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(99, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            18345015030859980000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(90, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(223, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(771, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(224, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(226, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(689, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(748, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(90, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(747, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(223, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(771, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(224, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(226, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(689, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(748, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4780546875801936000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(692, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(692, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(120, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(320, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(746, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(171, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(693, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(319, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(170, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(690, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(690, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(120, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(320, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(746, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(321, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(171, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(691, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(319, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            18345022727441380000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(692, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(120, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(320, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(279, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(171, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(117, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(693, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(171, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(693, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(319, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(690, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(120, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(320, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(279, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(171, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(117, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(691, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(171, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(691, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(319, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            15336172135299617000,
+            vec![
+                DenEncounter::new(440, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(440, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(440, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(440, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(440, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(113, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(113, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(113, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(113, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(113, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(242, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(440, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(440, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(440, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(440, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(440, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(113, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(113, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(113, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(113, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(113, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(242, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            11188576017968090000,
+            vec![
+                DenEncounter::new(113, 0, 1, 2, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(113, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(113, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(113, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(113, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(113, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(113, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 1, 2, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(113, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(113, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(113, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(113, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(113, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(113, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            16882931869395425000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(415, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(415, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(415, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(415, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(415, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(415, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(415, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(415, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(416, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4515385547978136000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(415, 0, 1, 2, 2, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 1, 2, 2, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 2, 2, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(415, 0, 2, 2, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(416, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(416, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(415, 0, 1, 2, 2, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 1, 2, 2, false, [true, true, false, false, false]),
+                DenEncounter::new(415, 0, 2, 2, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(415, 0, 2, 2, 2, false, [false, true, true, false, false]),
+                DenEncounter::new(416, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(416, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(416, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701088864462886000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(590, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(102, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(114, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(315, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(114, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(315, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(103, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(3, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(465, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(407, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(3, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(590, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(102, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(114, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(315, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(114, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(315, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(103, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(3, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(465, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(407, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(3, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701092162997770000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(129, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(120, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(688, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(130, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(73, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(689, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(9, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(129, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(120, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(688, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(130, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(73, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(689, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(9, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701091063486142000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(98, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(223, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(224, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(713, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(614, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(99, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(99, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(98, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(688, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(223, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(224, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(713, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(614, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(99, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(99, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701094362021027000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(833, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(54, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(339, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(55, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(845, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(55, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(550, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(340, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(834, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(833, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(54, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(339, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(55, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(845, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(55, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(847, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(550, 1, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(340, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(834, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701093262509399000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(824, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(742, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(127, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(291, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(596, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(743, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(291, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(826, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(824, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(742, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(214, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(825, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(291, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(826, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(596, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(743, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(291, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(826, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701096561044283000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(843, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(529, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(843, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(529, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(28, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(552, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(844, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(530, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(844, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(843, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(529, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(843, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(529, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(28, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(552, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(844, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(530, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(553, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(844, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5701095461532656000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(840, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(761, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(420, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(840, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(762, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(841, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(820, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(841, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(820, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(763, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(841, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(840, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(761, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(420, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(840, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(762, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(842, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(820, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(842, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(820, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(763, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(842, 0, 4, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4665094036540599000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(132, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 3, 4, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(132, 0, 1, 3, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 2, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 3, 4, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(132, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            11519945754184083000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(132, 0, 1, 2, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(132, 0, 1, 2, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(132, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(132, 0, 4, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            15818376695778914000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(590, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(102, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(753, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(114, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(754, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(102, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(103, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(591, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(754, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(465, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(3, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(590, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(102, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(753, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(114, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(754, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(102, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(103, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(591, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(754, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(465, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(3, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7725829814153603000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(120, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(90, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(130, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(9, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(129, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(72, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(120, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(90, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(73, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(130, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(73, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(121, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(91, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(9, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            6162140483756004000,
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(744, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(744, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(745, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(745, 2, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(113, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(744, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(744, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(745, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(745, 1, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(745, 2, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            6162171270081594000,
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(744, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(744, 1, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(745, 0, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(745, 1, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(745, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(745, 1, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(745, 2, 4, 2, 0, false, [false, false, false, true, true]),
+            ],
+            vec![
+                DenEncounter::new(242, 0, 4, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(744, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 1, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(744, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 2, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(744, 0, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(744, 1, 3, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(745, 1, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(745, 0, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(745, 1, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(745, 0, 4, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(745, 2, 4, 2, 0, false, [false, false, false, true, true]),
+            ],
+        ),
+        Den::new(
+            2756478418053350400,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(333, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(831, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(333, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(446, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(820, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(832, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(334, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(832, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(333, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(831, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(333, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(446, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(820, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(832, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(334, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(832, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4769195437400349000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(333, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(819, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(333, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(820, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(820, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(832, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(334, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(832, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(143, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(333, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(819, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(333, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(820, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(820, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(832, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(334, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(832, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(143, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(143, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4197853775535533600,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(240, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(240, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(126, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(126, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(467, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(240, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(850, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(240, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(608, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(631, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(126, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(851, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(609, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(126, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(467, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7797506443826344000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(240, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(240, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(126, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(126, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(851, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(609, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(38, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(467, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(467, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(851, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(240, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(240, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(126, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(631, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(126, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(851, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(609, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(59, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(467, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(467, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(851, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1108881309583387400,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(138, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(349, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(138, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(349, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(340, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(550, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(139, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(550, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(350, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(369, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(140, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(349, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(140, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(349, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(340, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(550, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(141, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(550, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(350, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(369, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            2845993206239293000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(349, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(138, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(349, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(369, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(99, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(369, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(139, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(350, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(99, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(349, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(140, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(349, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(369, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(550, 1, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(99, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(369, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(141, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(350, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(130, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(99, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            16299909383459598000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(239, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(239, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(871, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(125, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(778, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(836, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(125, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(466, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(239, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(595, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(239, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(871, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(125, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(778, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(596, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(871, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(836, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(125, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(466, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4268295780237511000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(239, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(239, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(702, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(596, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(125, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(836, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(849, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(871, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(466, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(466, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(849, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(239, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(239, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(702, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(596, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(125, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(836, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(849, 1, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(871, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(466, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(466, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(849, 1, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            538718828553644350,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(347, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(347, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(345, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(347, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(830, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(348, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(346, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(829, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(345, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(345, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(347, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(345, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(830, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(346, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(752, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(830, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(598, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(348, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            10639252279486992000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(347, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(345, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(347, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(345, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(752, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(12, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(348, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(346, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(830, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(348, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(12, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(345, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(347, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(345, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(347, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(752, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(12, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(346, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(348, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(830, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(346, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(12, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7520360650147353000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(238, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(554, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(238, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(698, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(221, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(460, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(124, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(555, 2, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(699, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(362, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(238, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(220, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(238, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(698, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(221, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(460, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(124, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(699, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(362, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            3231560995259523000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(361, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(554, 1, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(238, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(698, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(362, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(460, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(124, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(555, 2, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(699, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(473, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(361, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(361, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(238, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(698, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(362, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(460, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(124, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(873, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(699, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(473, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            7520357351612468000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(613, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(363, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(363, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(712, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(364, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(615, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(614, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(365, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(713, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(875, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(363, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(363, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(875, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(364, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(615, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(584, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(614, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(365, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(713, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1345818289025325000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(131, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(363, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(364, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(364, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(615, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(365, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(584, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(365, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(131, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(363, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(364, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(364, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(713, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(615, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(365, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(131, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(584, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(365, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(131, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            8444690290455067000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(532, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(533, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(534, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(623, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(623, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(534, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(532, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(622, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(533, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(534, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(623, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(623, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(534, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            221992188589330700,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(870, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(236, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(533, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(83, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(870, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(865, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(534, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(106, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(237, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(68, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(870, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(236, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(533, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(533, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(870, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(623, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(534, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(534, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(107, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(237, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(68, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5955975221769392000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(29, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(41, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(29, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(32, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(30, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(33, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(31, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(42, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(42, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(31, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(34, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(32, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(41, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(32, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(29, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(33, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(30, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(34, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(42, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(42, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(34, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(31, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            14450795946632080000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(41, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(568, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(30, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(79, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(569, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(31, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(31, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(34, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(169, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(569, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(41, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(568, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(33, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(79, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(569, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(34, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(34, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(31, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(169, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(569, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4805937820974168000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(41, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(333, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(822, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(42, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(715, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(169, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(41, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(714, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(333, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(822, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(42, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(715, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(169, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            11331443048367530000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(41, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(822, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(528, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(823, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(142, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(169, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(715, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(823, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(41, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(527, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(822, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(42, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(528, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(823, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(142, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(334, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(169, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(715, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(823, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1716759284250366200,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(343, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(436, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(122, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(575, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(344, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 0, 4, 4, 1, false, [false, false, false, true, true]),
+                DenEncounter::new(576, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(344, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(437, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(439, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(77, 1, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(436, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(122, 1, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(578, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(344, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 1, 4, 4, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(579, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(78, 1, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(437, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            12829170745926814000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(876, 0, 2, 2, 1, false, [true, true, false, false, false]),
+                DenEncounter::new(574, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(122, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(79, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(375, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 0, 4, 2, 1, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 0, 5, 2, 1, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(576, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(576, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(376, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(876, 1, 2, 2, 2, false, [true, true, false, false, false]),
+                DenEncounter::new(577, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(122, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(79, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(375, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 1, 4, 2, 2, false, [false, false, true, true, false]),
+                DenEncounter::new(876, 1, 5, 2, 2, false, [false, false, false, true, true]),
+                DenEncounter::new(866, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(579, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(579, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(376, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            6189149299220963000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(874, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(874, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(874, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(305, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(303, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(305, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(703, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(837, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(838, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(838, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(305, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(305, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(703, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(839, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            744948697234498200,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(303, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(305, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(305, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(213, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(303, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(303, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(839, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(302, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(305, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(305, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(213, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(839, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(839, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            5162770839310267000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(885, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(708, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(778, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(886, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(778, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(303, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(362, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(478, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(709, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(778, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(885, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(708, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(778, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(361, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(886, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(778, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(302, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(362, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(478, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(709, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(778, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            11690997354028680000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(885, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(93, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(303, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(709, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(709, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(303, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(887, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(887, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(94, 0, 5, 2, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(885, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(885, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(222, 1, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(302, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(709, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(864, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(302, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(887, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(887, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(94, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            4408860220788168700,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(621, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(371, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(371, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(696, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(372, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(147, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(372, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(621, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(697, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(373, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(373, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(621, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(443, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(443, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(696, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(444, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(147, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(444, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(621, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(697, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(445, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(445, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            18001771904838230000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(884, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(371, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(696, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(776, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(372, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(884, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(149, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(697, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(373, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(373, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(884, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(884, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(443, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(696, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(780, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(444, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(884, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(149, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(697, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(445, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(445, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(884, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            11147942343095867000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(215, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(264, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(359, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(359, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(461, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(215, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(859, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(860, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(215, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(264, 1, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(861, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(359, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(862, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(359, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(461, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            1812702195150859500,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(859, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(359, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(215, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(828, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(510, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(359, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(461, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(359, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(861, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(859, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(359, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(215, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(215, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(828, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(510, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(359, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(861, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(461, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(359, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(861, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            9125837977236589000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(304, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(632, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(374, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(305, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(375, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(632, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(306, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(304, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(632, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(304, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(374, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(305, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(375, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(823, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(632, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(879, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(306, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            16150871691787878000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(304, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(52, 2, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(632, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(305, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(863, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(863, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(879, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(376, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(376, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(879, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(304, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(52, 2, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(632, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(305, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(863, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(863, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(879, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(306, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(376, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(376, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(879, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            14439216054291850000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(173, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(703, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(173, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(857, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(35, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(703, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(703, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(36, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(173, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(703, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(856, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(173, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(857, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(35, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(703, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(703, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(36, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(547, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            8284890978883699000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(703, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(546, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(35, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(703, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(703, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(547, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(110, 1, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(858, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(36, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(703, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(546, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(35, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(703, 0, 3, 2, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(703, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(547, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(110, 1, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(858, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(36, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(110, 1, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(858, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            17302261471610569000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(854, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(854, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(854, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(854, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(854, 1, 4, 4, 0, false, [false, false, true, true, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(854, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(854, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(854, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(854, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(854, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(854, 1, 4, 4, 0, false, [false, false, true, true, true]),
+            ],
+        ),
+        Den::new(
+            10041392713565151000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(854, 0, 2, 2, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 2, 2, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(854, 0, 3, 2, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(854, 0, 3, 2, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(854, 0, 4, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(854, 0, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(854, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 1, 5, 2, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(855, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(855, 1, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(869, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(854, 0, 2, 2, 0, false, [true, false, false, false, false]),
+                DenEncounter::new(854, 0, 2, 2, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(854, 0, 3, 2, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(854, 0, 3, 2, 0, false, [false, true, false, false, false]),
+                DenEncounter::new(854, 0, 4, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(854, 0, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(869, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(854, 1, 5, 2, 0, false, [true, true, true, true, false]),
+                DenEncounter::new(855, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(855, 1, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(869, 0, 5, 2, 0, true, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            16685003352010291000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(133, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(133, 0, 2, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(133, 0, 3, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(136, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(135, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(134, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(196, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(197, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(470, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(471, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(700, 0, 4, 4, 0, false, [false, false, true, true, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(133, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(133, 0, 2, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(133, 0, 3, 3, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(136, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(135, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(134, 0, 4, 3, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(196, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(197, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(470, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(471, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(700, 0, 4, 4, 0, false, [false, false, true, true, true]),
+            ],
+        ),
+        Den::new(
+            13686551123076485000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(133, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(133, 0, 3, 2, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(133, 0, 4, 2, 2, false, [true, true, true, true, true]),
+                DenEncounter::new(136, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(135, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(134, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(196, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(197, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(470, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(471, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(700, 0, 5, 2, 0, false, [false, false, true, true, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(133, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(133, 0, 3, 2, 0, false, [true, true, true, true, true]),
+                DenEncounter::new(133, 0, 4, 2, 2, false, [true, true, true, true, true]),
+                DenEncounter::new(136, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(135, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(134, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(196, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(197, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(470, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(471, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(700, 0, 5, 2, 0, false, [false, false, true, true, true]),
+            ],
+        ),
+        Den::new(
+            6395957127820209000,
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(138, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(347, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(696, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(698, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(348, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(346, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(139, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(348, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(697, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(699, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(142, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+            vec![
+                DenEncounter::new(531, 0, 4, 4, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(140, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(345, 0, 1, 3, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(698, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(696, 0, 2, 3, 0, false, [false, true, true, false, false]),
+                DenEncounter::new(346, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(348, 0, 3, 3, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(141, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(346, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(699, 0, 4, 4, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(697, 0, 4, 4, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(142, 0, 4, 4, 0, false, [false, false, false, false, true]),
+            ],
+        ),
+        Den::new(
+            13032247726971474000,
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(138, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(139, 0, 2, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(142, 0, 3, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(881, 0, 3, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(880, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(882, 0, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(142, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(880, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(882, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(881, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(883, 0, 5, 2, 0, false, [false, false, false, true, true]),
+            ],
+            vec![
+                DenEncounter::new(225, 0, 5, 2, 0, false, [false, false, true, true, true]),
+                DenEncounter::new(140, 0, 2, 2, 0, false, [true, true, false, false, false]),
+                DenEncounter::new(141, 0, 2, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(142, 0, 3, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(883, 0, 3, 2, 0, false, [false, false, true, false, false]),
+                DenEncounter::new(882, 0, 4, 2, 0, false, [false, false, true, true, false]),
+                DenEncounter::new(880, 0, 4, 2, 0, false, [false, false, false, true, false]),
+                DenEncounter::new(142, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(882, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(880, 0, 5, 2, 0, false, [false, false, false, false, true]),
+                DenEncounter::new(883, 0, 5, 2, 0, false, [false, false, false, true, true]),
+                DenEncounter::new(881, 0, 5, 2, 0, false, [false, false, false, true, true]),
+            ],
+        ),
+    ]);
+}
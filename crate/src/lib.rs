@@ -1,11 +1,18 @@
 use wasm_bindgen::prelude::*;
 
+mod ability_data;
 mod core;
+mod den_data;
 mod personal_data;
+mod static_data;
 
+use self::core::crystal::{self, CrystalTable};
+use self::core::event;
 use self::core::filter::FrameFilter;
 use self::core::frame::{Frame, FrameGenerator, FrameResult};
+use self::core::planner::{self, Date};
 use self::core::raid::Raid;
+use self::core::slot::{self, SlotTables};
 use js_sys;
 use std::iter::FromIterator;
 
@@ -71,6 +78,42 @@ pub fn search(raid: Raid, seed: u64, filter: FrameFilter) -> Option<SearchResult
     }
 }
 
+/// Loads event raids from a JSON dump of the nest event encounter table.
+#[wasm_bindgen]
+pub fn load_event_raids(json: &str) -> Result<js_sys::Array, JsValue> {
+    event::load_event_raids(json)
+        .map(|raids| js_sys::Array::from_iter(raids.into_iter().map(JsValue::from)))
+        .map_err(|e| JsValue::from(e.to_string()))
+}
+
+/// Loads the Dynamax Crystal catalogue from a JSON dump of the crystal encounter table.
+#[wasm_bindgen]
+pub fn load_crystals(json: &str) -> Result<CrystalTable, JsValue> {
+    crystal::load_crystals(json).map_err(|e| JsValue::from(e.to_string()))
+}
+
+/// Loads overworld encounter tables from a JSON dump of the game's encounter tables.
+#[wasm_bindgen]
+pub fn load_slot_tables(json: &str) -> Result<SlotTables, JsValue> {
+    slot::load_slot_tables(json).map_err(|e| JsValue::from(e.to_string()))
+}
+
+/// Plans the date skips, saves and checks needed to reach a target frame from a seed,
+/// starting on the given date.
+#[wasm_bindgen]
+pub fn plan_date_skips(
+    seed: u64,
+    target: u32,
+    date: Date,
+    checkpoint_interval: u32,
+) -> js_sys::Array {
+    let steps = planner::plan_date_skips(seed, target, date, checkpoint_interval)
+        .into_iter()
+        .map(JsValue::from);
+
+    js_sys::Array::from_iter(steps)
+}
+
 #[cfg(test)]
 mod test {
     use self::core::filter::{
@@ -82,7 +125,7 @@ mod test {
     #[test]
     fn test_iv_search() {
         let result = search(
-            Raid::new(346, 0, 4, false, 4, 0), // 5* Cradily, Den 166,
+            Raid::new(346, 0, 4, false, 4, 0, 0, 5), // 5* Cradily, Den 166,
             0xbb810e6006a2a035,
             FrameFilter::new().set_ivs(
                 Some(SingleIVFilter::new_at_least(IVJudgment::Best)),
@@ -100,7 +143,7 @@ mod test {
     #[test]
     fn test_timeout_search() {
         let result = search(
-            Raid::new(346, 0, 4, false, 4, 0), // 5* Cradily, Den 166,
+            Raid::new(346, 0, 4, false, 4, 0, 0, 5), // 5* Cradily, Den 166,
             0xbb810e6006a2a035,
             FrameFilter::new()
                 .set_shiny(ShinyFilter::Square)
@@ -39,8 +39,8 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(35, 55, 40, 90, 50, 50, (0, 236), 127, 9, 9, 31, 901, 10, true),
         Mon::new(60, 90, 55, 110, 90, 80, (0, 0), 127, 9, 9, 31, 910, 2, true),
-        Mon::new(50, 75, 85, 40, 20, 30, (0, 30), 127, 8, 8, 146, 911, 2, true),
-        Mon::new(75, 100, 110, 65, 45, 55, (0, 30), 127, 8, 8, 146, 912, 2, true),
+        Mon::new(50, 75, 85, 40, 20, 30, (0, 286), 127, 8, 8, 146, 911, 2, true),
+        Mon::new(75, 100, 110, 65, 45, 55, (0, 286), 127, 8, 8, 146, 912, 2, true),
         Mon::new(55, 47, 52, 41, 40, 40, (0, 0), 254, 38, 79, 55, 0, 1, true),
         Mon::new(70, 62, 67, 56, 55, 55, (0, 0), 254, 38, 79, 55, 0, 1, true),
         Mon::new(90, 92, 87, 76, 75, 85, (0, 0), 254, 38, 79, 125, 0, 1, true),
@@ -55,9 +55,9 @@ lazy_static! {
         Mon::new(140, 70, 45, 45, 85, 50, (0, 81), 191, 56, 172, 119, 0, 1, true),
         Mon::new(40, 45, 35, 55, 30, 40, (0, 0), 127, 39, 39, 151, 0, 1, true),
         Mon::new(75, 80, 70, 90, 65, 75, (0, 0), 127, 39, 39, 151, 0, 1, true),
-        Mon::new(45, 50, 55, 30, 75, 65, (0, 33), 127, 34, 34, 50, 0, 1, true),
-        Mon::new(60, 65, 70, 40, 85, 75, (0, 33), 127, 34, 34, 1, 0, 1, true),
-        Mon::new(75, 80, 85, 50, 110, 90, (0, 33), 127, 34, 34, 27, 0, 1, true),
+        Mon::new(45, 50, 55, 30, 75, 65, (0, 545), 127, 34, 34, 50, 0, 1, true),
+        Mon::new(60, 65, 70, 40, 85, 75, (0, 545), 127, 34, 34, 1, 0, 1, true),
+        Mon::new(75, 80, 85, 50, 110, 90, (0, 545), 127, 34, 34, 27, 0, 1, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
@@ -91,11 +91,11 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(50, 85, 55, 90, 65, 65, (0, 0), 127, 50, 18, 49, 923, 2, true),
         Mon::new(65, 100, 70, 105, 80, 80, (0, 0), 127, 50, 18, 49, 924, 2, true),
-        Mon::new(90, 65, 65, 15, 40, 40, (0, 23), 127, 12, 20, 144, 925, 2, true),
+        Mon::new(90, 65, 65, 15, 40, 40, (0, 279), 127, 12, 20, 144, 925, 2, true),
         Mon::new(95, 75, 110, 30, 100, 80, (0, 221), 127, 12, 20, 144, 926, 3, true),
         Mon::new(25, 35, 70, 45, 95, 55, (0, 233), 255, 42, 5, 148, 0, 1, true),
         Mon::new(50, 60, 95, 70, 120, 70, (0, 233), 255, 42, 5, 148, 0, 1, true),
-        Mon::new(52, 90, 55, 60, 58, 62, (0, 3), 127, 51, 39, 128, 928, 2, true),
+        Mon::new(52, 90, 55, 60, 58, 62, (0, 259), 127, 51, 39, 128, 928, 2, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
@@ -114,13 +114,13 @@ lazy_static! {
         Mon::new(55, 130, 115, 75, 50, 50, (0, 0), 127, 52, 75, 125, 0, 1, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
-        Mon::new(60, 40, 80, 40, 60, 45, (0, 114), 127, 34, 34, 139, 0, 1, true),
+        Mon::new(60, 40, 80, 40, 60, 45, (0, 882), 127, 34, 34, 139, 0, 1, true),
         Mon::new(95, 95, 85, 55, 125, 75, (0, 0), 127, 34, 34, 139, 931, 2, true),
-        Mon::new(50, 50, 95, 35, 40, 50, (0, 2), 127, 69, 31, 4, 0, 1, true),
-        Mon::new(60, 80, 110, 45, 50, 80, (0, 2), 127, 69, 31, 4, 932, 2, true),
+        Mon::new(50, 50, 95, 35, 40, 50, (0, 258), 127, 69, 31, 4, 0, 1, true),
+        Mon::new(60, 80, 110, 45, 50, 80, (0, 258), 127, 69, 31, 4, 932, 2, true),
         Mon::new(50, 120, 53, 87, 35, 110, (0, 0), 0, 7, 120, 84, 0, 1, true),
         Mon::new(50, 105, 79, 76, 35, 110, (0, 0), 0, 51, 89, 39, 0, 1, true),
-        Mon::new(90, 55, 75, 30, 60, 75, (0, 23), 127, 20, 12, 13, 0, 1, true),
+        Mon::new(90, 55, 75, 30, 60, 75, (0, 279), 127, 20, 12, 13, 0, 1, true),
         Mon::new(40, 65, 95, 35, 60, 45, (0, 228), 127, 26, 256, 1, 0, 1, true),
         Mon::new(65, 90, 120, 60, 85, 70, (0, 228), 127, 26, 256, 1, 933, 2, true),
         Mon::new(80, 85, 95, 25, 30, 30, (0, 0), 127, 31, 69, 120, 0, 1, true),
@@ -137,14 +137,14 @@ lazy_static! {
         Mon::new(40, 45, 65, 90, 100, 120, (0, 0), 127, 43, 111, 101, 934, 2, true),
         Mon::new(70, 110, 80, 105, 55, 80, (0, 0), 127, 68, 101, 80, 0, 1, true),
         Mon::new(65, 50, 35, 95, 115, 95, (0, 0), 254, 12, 108, 87, 0, 1, true),
-        Mon::new(65, 83, 57, 105, 95, 85, (0, 66), 63, 9, 9, 72, 0, 1, true),
-        Mon::new(65, 95, 57, 93, 100, 85, (0, 67), 63, 49, 49, 72, 0, 1, true),
+        Mon::new(65, 83, 57, 105, 95, 85, (0, 322), 63, 9, 9, 72, 0, 1, true),
+        Mon::new(65, 95, 57, 93, 100, 85, (0, 323), 63, 49, 49, 72, 0, 1, true),
         Mon::new(65, 125, 100, 85, 55, 70, (0, 0), 127, 52, 104, 153, 0, 1, true),
         Mon::new(75, 100, 95, 110, 40, 70, (0, 0), 0, 22, 83, 125, 0, 1, true),
         Mon::new(20, 10, 55, 80, 15, 20, (0, 0), 127, 33, 33, 155, 0, 1, true),
         Mon::new(95, 125, 79, 81, 60, 100, (0, 0), 127, 22, 22, 153, 0, 1, true),
         Mon::new(130, 85, 80, 60, 85, 95, (243, 243), 127, 11, 75, 93, 0, 1, true),
-        Mon::new(48, 48, 48, 48, 48, 48, (18, 1), 255, 7, 7, 150, 0, 1, true),
+        Mon::new(48, 48, 48, 48, 48, 48, (274, 257), 255, 7, 7, 150, 0, 1, true),
         Mon::new(55, 55, 50, 55, 45, 65, (0, 0), 31, 50, 91, 107, 0, 1, true),
         Mon::new(130, 65, 60, 65, 110, 95, (0, 0), 31, 11, 11, 93, 0, 1, true),
         Mon::new(65, 65, 60, 130, 110, 95, (0, 0), 31, 10, 10, 95, 0, 1, true),
@@ -194,7 +194,7 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
-        Mon::new(75, 80, 95, 50, 90, 100, (0, 33), 127, 34, 34, 131, 0, 1, true),
+        Mon::new(75, 80, 95, 50, 90, 100, (0, 545), 127, 34, 34, 131, 0, 1, true),
         Mon::new(70, 20, 50, 40, 20, 50, (0, 0), 127, 47, 37, 157, 0, 1, true),
         Mon::new(100, 50, 80, 50, 60, 80, (0, 0), 127, 47, 37, 157, 0, 1, true),
         Mon::new(70, 100, 115, 30, 30, 65, (0, 0), 127, 5, 69, 155, 0, 1, true),
@@ -234,7 +234,7 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(50, 50, 40, 50, 30, 30, (0, 0), 127, 12, 81, 47, 0, 1, true),
         Mon::new(100, 100, 80, 50, 60, 60, (0, 0), 127, 12, 81, 47, 0, 1, true),
-        Mon::new(65, 55, 95, 35, 65, 95, (0, 136), 191, 55, 30, 144, 966, 2, true),
+        Mon::new(65, 55, 95, 35, 65, 95, (0, 648), 191, 55, 30, 144, 966, 2, true),
         Mon::new(35, 65, 35, 65, 65, 35, (0, 0), 127, 55, 97, 141, 0, 1, true),
         Mon::new(75, 105, 75, 45, 105, 75, (0, 0), 127, 21, 97, 141, 0, 1, true),
         Mon::new(45, 55, 45, 75, 65, 45, (0, 0), 127, 72, 55, 15, 0, 1, true),
@@ -251,8 +251,8 @@ lazy_static! {
         Mon::new(35, 35, 35, 35, 35, 35, (0, 0), 0, 62, 80, 72, 0, 1, true),
         Mon::new(50, 95, 95, 70, 35, 110, (0, 0), 0, 22, 101, 80, 0, 1, true),
         Mon::new(45, 30, 15, 65, 85, 65, (0, 0), 254, 12, 108, 93, 0, 1, true),
-        Mon::new(45, 63, 37, 95, 65, 55, (0, 66), 63, 9, 9, 72, 0, 1, true),
-        Mon::new(45, 75, 37, 83, 70, 55, (0, 67), 63, 49, 49, 72, 0, 1, true),
+        Mon::new(45, 63, 37, 95, 65, 55, (0, 322), 63, 9, 9, 72, 0, 1, true),
+        Mon::new(45, 75, 37, 83, 70, 55, (0, 323), 63, 49, 49, 72, 0, 1, true),
         Mon::new(95, 80, 105, 100, 40, 70, (33, 33), 254, 47, 113, 157, 0, 1, true),
         Mon::new(255, 10, 10, 55, 75, 135, (0, 231), 254, 30, 32, 131, 0, 1, true),
         Mon::new(90, 85, 75, 115, 115, 100, (0, 0), 255, 46, 46, 39, 0, 1, true),
@@ -285,13 +285,13 @@ lazy_static! {
         Mon::new(40, 30, 30, 30, 40, 50, (0, 219), 127, 33, 44, 20, 0, 1, true),
         Mon::new(60, 50, 50, 50, 60, 70, (0, 219), 127, 33, 44, 20, 0, 1, true),
         Mon::new(80, 70, 70, 70, 90, 100, (0, 219), 127, 33, 44, 20, 0, 1, true),
-        Mon::new(40, 40, 50, 30, 30, 30, (0, 15), 127, 34, 48, 124, 0, 1, true),
-        Mon::new(70, 70, 40, 60, 60, 40, (0, 15), 127, 34, 48, 124, 0, 1, true),
-        Mon::new(90, 100, 60, 80, 90, 60, (0, 15), 127, 34, 48, 124, 0, 1, true),
+        Mon::new(40, 40, 50, 30, 30, 30, (0, 271), 127, 34, 48, 124, 0, 1, true),
+        Mon::new(70, 70, 40, 60, 60, 40, (0, 271), 127, 34, 48, 124, 0, 1, true),
+        Mon::new(90, 100, 60, 80, 90, 60, (0, 271), 127, 34, 48, 124, 0, 1, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
-        Mon::new(40, 30, 30, 85, 55, 30, (59, 0), 127, 51, 93, 44, 0, 1, true),
-        Mon::new(60, 50, 100, 65, 95, 70, (59, 0), 127, 51, 2, 44, 0, 1, true),
+        Mon::new(40, 30, 30, 85, 55, 30, (571, 0), 127, 51, 93, 44, 0, 1, true),
+        Mon::new(60, 50, 100, 65, 95, 70, (571, 0), 127, 51, 2, 44, 0, 1, true),
         Mon::new(28, 25, 25, 40, 45, 35, (0, 0), 127, 28, 36, 140, 0, 1, true),
         Mon::new(38, 35, 35, 50, 65, 55, (0, 0), 127, 28, 36, 140, 0, 1, true),
         Mon::new(68, 65, 65, 80, 125, 115, (0, 0), 127, 28, 36, 140, 0, 1, true),
@@ -314,8 +314,8 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
-        Mon::new(50, 75, 75, 50, 65, 65, (0, 9), 127, 51, 100, 158, 0, 1, true),
-        Mon::new(50, 85, 85, 50, 55, 55, (0, 22), 127, 52, 22, 125, 0, 1, true),
+        Mon::new(50, 75, 75, 50, 65, 65, (0, 265), 127, 51, 100, 158, 0, 1, true),
+        Mon::new(50, 85, 85, 50, 55, 55, (0, 278), 127, 52, 22, 125, 0, 1, true),
         Mon::new(50, 70, 100, 30, 40, 40, (0, 238), 127, 5, 69, 134, 0, 1, true),
         Mon::new(60, 90, 140, 40, 50, 50, (0, 238), 127, 5, 69, 134, 0, 1, true),
         Mon::new(70, 110, 180, 50, 60, 60, (0, 238), 127, 5, 69, 134, 0, 1, true),
@@ -355,10 +355,10 @@ lazy_static! {
         Mon::new(110, 78, 73, 60, 76, 71, (0, 0), 127, 12, 107, 93, 0, 1, true),
         Mon::new(43, 80, 65, 35, 50, 35, (0, 0), 127, 52, 75, 91, 0, 1, true),
         Mon::new(63, 120, 85, 55, 90, 55, (0, 0), 127, 52, 75, 91, 0, 1, true),
-        Mon::new(40, 40, 55, 55, 40, 70, (0, 13), 255, 26, 26, 26, 0, 1, true),
-        Mon::new(60, 70, 105, 75, 70, 120, (0, 13), 255, 26, 26, 26, 0, 1, true),
-        Mon::new(66, 41, 77, 23, 61, 87, (0, 40), 31, 21, 21, 114, 0, 1, true),
-        Mon::new(86, 81, 97, 43, 81, 107, (0, 40), 31, 21, 21, 114, 0, 1, true),
+        Mon::new(40, 40, 55, 55, 40, 70, (0, 269), 255, 26, 26, 26, 0, 1, true),
+        Mon::new(60, 70, 105, 75, 70, 120, (0, 269), 255, 26, 26, 26, 0, 1, true),
+        Mon::new(66, 41, 77, 23, 61, 87, (0, 296), 31, 21, 21, 114, 0, 1, true),
+        Mon::new(86, 81, 97, 43, 81, 107, (0, 296), 31, 21, 21, 114, 0, 1, true),
         Mon::new(45, 95, 50, 75, 40, 50, (0, 0), 31, 4, 4, 33, 0, 1, true),
         Mon::new(75, 125, 100, 45, 70, 80, (0, 0), 31, 4, 4, 33, 0, 1, true),
         Mon::new(20, 15, 20, 80, 10, 55, (0, 0), 127, 33, 12, 91, 0, 1, true),
@@ -373,7 +373,7 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(65, 130, 60, 75, 75, 60, (0, 0), 127, 46, 105, 154, 0, 1, true),
         Mon::new(95, 23, 48, 23, 23, 48, (0, 0), 127, 23, 23, 140, 0, 1, true),
-        Mon::new(50, 50, 50, 50, 50, 50, (0, 137), 127, 39, 115, 141, 0, 1, true),
+        Mon::new(50, 50, 50, 50, 50, 50, (0, 649), 127, 39, 115, 141, 0, 1, true),
         Mon::new(80, 80, 80, 80, 80, 80, (0, 0), 127, 39, 115, 141, 0, 1, true),
         Mon::new(70, 40, 50, 25, 55, 50, (0, 0), 127, 47, 115, 12, 0, 1, true),
         Mon::new(90, 60, 70, 45, 75, 70, (0, 0), 127, 47, 115, 12, 0, 1, true),
@@ -465,8 +465,8 @@ lazy_static! {
         Mon::new(108, 112, 118, 47, 68, 72, (0, 0), 127, 45, 45, 159, 0, 1, true),
         Mon::new(40, 50, 90, 65, 30, 55, (0, 245), 127, 4, 97, 51, 0, 1, true),
         Mon::new(70, 90, 110, 95, 60, 75, (0, 245), 127, 4, 97, 51, 0, 1, true),
-        Mon::new(48, 61, 40, 50, 61, 40, (0, 25), 127, 107, 87, 143, 0, 1, true),
-        Mon::new(83, 106, 65, 85, 86, 65, (0, 25), 127, 107, 87, 143, 0, 1, true),
+        Mon::new(48, 61, 40, 50, 61, 40, (0, 281), 127, 107, 87, 143, 0, 1, true),
+        Mon::new(83, 106, 65, 85, 86, 65, (0, 281), 127, 107, 87, 143, 0, 1, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
@@ -475,11 +475,11 @@ lazy_static! {
         Mon::new(90, 92, 75, 60, 92, 85, (0, 246), 127, 117, 117, 43, 0, 1, true),
         Mon::new(70, 120, 65, 125, 45, 85, (0, 217), 127, 46, 46, 124, 0, 1, true),
         Mon::new(70, 70, 115, 60, 130, 90, (0, 233), 255, 42, 5, 148, 0, 1, true),
-        Mon::new(110, 85, 95, 50, 80, 95, (0, 23), 127, 20, 12, 13, 0, 1, true),
+        Mon::new(110, 85, 95, 50, 80, 95, (0, 279), 127, 20, 12, 13, 0, 1, true),
         Mon::new(115, 140, 130, 40, 55, 55, (0, 0), 127, 31, 116, 120, 0, 1, true),
         Mon::new(100, 100, 125, 50, 110, 50, (0, 0), 127, 34, 102, 144, 0, 1, true),
-        Mon::new(75, 123, 67, 95, 95, 85, (0, 66), 63, 78, 78, 72, 0, 1, true),
-        Mon::new(75, 95, 67, 83, 125, 95, (0, 67), 63, 49, 49, 72, 0, 1, true),
+        Mon::new(75, 123, 67, 95, 95, 85, (0, 322), 63, 78, 78, 72, 0, 1, true),
+        Mon::new(75, 95, 67, 83, 125, 95, (0, 323), 63, 49, 49, 72, 0, 1, true),
         Mon::new(85, 50, 95, 80, 120, 115, (0, 0), 31, 55, 32, 105, 0, 1, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(65, 110, 130, 95, 60, 65, (0, 0), 31, 102, 102, 34, 0, 1, true),
@@ -558,10 +558,10 @@ lazy_static! {
         Mon::new(30, 45, 59, 57, 30, 39, (0, 245), 127, 38, 68, 3, 0, 1, true),
         Mon::new(40, 55, 99, 47, 40, 79, (0, 245), 127, 38, 68, 3, 0, 1, true),
         Mon::new(60, 100, 89, 112, 55, 69, (0, 245), 127, 38, 68, 3, 0, 1, true),
-        Mon::new(40, 27, 60, 66, 37, 50, (0, 33), 127, 158, 151, 34, 0, 1, true),
-        Mon::new(60, 67, 85, 116, 77, 75, (0, 33), 127, 158, 151, 34, 0, 1, true),
-        Mon::new(45, 35, 50, 30, 70, 50, (0, 33), 254, 34, 20, 102, 0, 1, true),
-        Mon::new(70, 60, 75, 90, 110, 75, (0, 33), 254, 34, 20, 102, 0, 1, true),
+        Mon::new(40, 27, 60, 66, 37, 50, (0, 545), 127, 158, 151, 34, 0, 1, true),
+        Mon::new(60, 67, 85, 116, 77, 75, (0, 545), 127, 158, 151, 34, 0, 1, true),
+        Mon::new(45, 35, 50, 30, 70, 50, (0, 545), 254, 34, 20, 102, 0, 1, true),
+        Mon::new(70, 60, 75, 90, 110, 75, (0, 545), 254, 34, 20, 102, 0, 1, true),
         Mon::new(70, 92, 65, 98, 80, 55, (0, 0), 127, 120, 91, 104, 1009, 2, true),
         Mon::new(50, 72, 35, 65, 35, 35, (0, 240), 127, 22, 153, 83, 0, 1, true),
         Mon::new(60, 82, 45, 74, 45, 45, (0, 240), 127, 22, 153, 83, 0, 1, true),
@@ -571,8 +571,8 @@ lazy_static! {
         Mon::new(75, 86, 67, 60, 106, 67, (0, 239), 127, 11, 34, 114, 0, 1, true),
         Mon::new(50, 65, 85, 55, 35, 35, (0, 238), 127, 5, 75, 133, 0, 1, true),
         Mon::new(70, 105, 125, 45, 65, 75, (0, 238), 127, 5, 75, 133, 0, 1, true),
-        Mon::new(50, 75, 70, 48, 35, 70, (0, 39), 127, 61, 153, 22, 0, 1, true),
-        Mon::new(65, 90, 115, 58, 45, 115, (0, 39), 127, 61, 153, 22, 0, 1, true),
+        Mon::new(50, 75, 70, 48, 35, 70, (0, 295), 127, 61, 153, 22, 0, 1, true),
+        Mon::new(65, 90, 115, 58, 45, 115, (0, 295), 127, 61, 153, 22, 0, 1, true),
         Mon::new(72, 58, 80, 97, 103, 80, (0, 0), 127, 147, 98, 110, 0, 1, true),
         Mon::new(38, 30, 85, 30, 55, 65, (0, 247), 127, 152, 152, 152, 1014, 2, true),
         Mon::new(58, 50, 145, 30, 95, 105, (0, 247), 127, 152, 152, 152, 0, 1, true),
@@ -581,7 +581,7 @@ lazy_static! {
         Mon::new(55, 112, 45, 70, 74, 45, (0, 0), 31, 129, 129, 129, 0, 1, true),
         Mon::new(75, 140, 65, 110, 112, 65, (0, 0), 31, 129, 129, 129, 0, 1, true),
         Mon::new(50, 50, 62, 65, 40, 62, (0, 251), 127, 1, 60, 106, 0, 1, true),
-        Mon::new(80, 95, 82, 75, 60, 82, (251, 25), 127, 1, 133, 106, 0, 1, true),
+        Mon::new(80, 95, 82, 75, 60, 82, (251, 281), 127, 1, 133, 106, 0, 1, true),
         Mon::new(40, 65, 40, 65, 80, 40, (0, 0), 31, 149, 149, 149, 0, 1, true),
         Mon::new(60, 105, 60, 105, 120, 60, (0, 0), 31, 149, 149, 149, 0, 1, true),
         Mon::new(55, 50, 40, 75, 40, 40, (0, 0), 191, 56, 101, 92, 0, 1, true),
@@ -609,8 +609,8 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(50, 47, 50, 65, 57, 50, (0, 0), 127, 14, 127, 68, 0, 1, true),
         Mon::new(70, 77, 60, 108, 97, 60, (0, 0), 127, 14, 127, 68, 0, 1, true),
-        Mon::new(44, 50, 91, 10, 24, 86, (0, 32), 127, 160, 160, 160, 0, 1, true),
-        Mon::new(74, 94, 131, 20, 54, 116, (0, 32), 127, 160, 160, 107, 0, 1, true),
+        Mon::new(44, 50, 91, 10, 24, 86, (0, 288), 127, 160, 160, 160, 0, 1, true),
+        Mon::new(74, 94, 131, 20, 54, 116, (0, 288), 127, 160, 160, 107, 0, 1, true),
         Mon::new(40, 55, 70, 30, 45, 60, (0, 0), 255, 57, 58, 29, 0, 1, true),
         Mon::new(60, 80, 95, 50, 70, 85, (0, 0), 255, 57, 58, 29, 0, 1, true),
         Mon::new(60, 100, 115, 90, 70, 85, (0, 0), 255, 57, 58, 29, 0, 1, true),
@@ -634,8 +634,8 @@ lazy_static! {
         Mon::new(45, 85, 50, 65, 55, 50, (0, 0), 127, 39, 144, 120, 0, 1, true),
         Mon::new(65, 125, 60, 105, 95, 60, (0, 0), 127, 39, 144, 120, 0, 1, true),
         Mon::new(77, 120, 90, 48, 60, 90, (0, 250), 127, 24, 125, 104, 0, 1, true),
-        Mon::new(59, 74, 50, 35, 35, 50, (0, 13), 255, 89, 103, 99, 0, 1, true),
-        Mon::new(89, 124, 80, 55, 55, 80, (0, 13), 255, 89, 103, 99, 0, 1, true),
+        Mon::new(59, 74, 50, 35, 35, 50, (0, 269), 255, 89, 103, 99, 0, 1, true),
+        Mon::new(89, 124, 80, 55, 55, 80, (0, 269), 255, 89, 103, 99, 0, 1, true),
         Mon::new(45, 85, 70, 60, 40, 40, (0, 0), 127, 128, 39, 46, 0, 1, true),
         Mon::new(65, 125, 100, 70, 60, 70, (0, 0), 127, 128, 39, 46, 0, 1, true),
         Mon::new(95, 110, 95, 55, 40, 95, (0, 0), 127, 120, 157, 43, 0, 1, true),
@@ -716,8 +716,8 @@ lazy_static! {
         Mon::new(78, 92, 75, 118, 74, 63, (0, 221), 127, 7, 84, 104, 0, 1, true),
         Mon::new(67, 58, 57, 101, 81, 67, (0, 0), 127, 167, 53, 57, 0, 1, true),
         Mon::new(50, 50, 150, 50, 50, 150, (0, 0), 255, 29, 29, 5, 0, 1, true),
-        Mon::new(45, 50, 35, 40, 55, 75, (0, 39), 127, 157, 93, 183, 0, 1, true),
-        Mon::new(68, 75, 53, 60, 83, 113, (0, 39), 127, 157, 93, 183, 0, 1, true),
+        Mon::new(45, 50, 35, 40, 55, 75, (0, 295), 127, 157, 93, 183, 0, 1, true),
+        Mon::new(68, 75, 53, 60, 83, 113, (0, 295), 127, 157, 93, 183, 0, 1, true),
         Mon::new(90, 100, 70, 80, 110, 150, (0, 0), 127, 157, 93, 183, 0, 1, true),
         Mon::new(57, 80, 91, 75, 80, 87, (0, 0), 127, 158, 158, 170, 0, 1, true),
         Mon::new(43, 70, 48, 38, 50, 60, (0, 0), 127, 30, 119, 139, 0, 1, true),
@@ -749,7 +749,7 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(47, 62, 45, 46, 55, 45, (0, 0), 127, 68, 68, 68, 0, 1, true),
-        Mon::new(57, 82, 95, 36, 55, 75, (0, 34), 127, 217, 217, 217, 0, 1, true),
+        Mon::new(57, 82, 95, 36, 55, 75, (0, 546), 127, 217, 217, 217, 0, 1, true),
         Mon::new(77, 70, 90, 43, 145, 75, (0, 0), 127, 26, 26, 26, 0, 1, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
@@ -761,8 +761,8 @@ lazy_static! {
         Mon::new(45, 20, 20, 40, 25, 25, (0, 0), 127, 208, 208, 208, 1134, 2, true),
         Mon::new(50, 53, 62, 45, 43, 52, (0, 245), 127, 196, 7, 144, 0, 1, true),
         Mon::new(50, 63, 152, 35, 53, 142, (0, 245), 127, 196, 7, 144, 0, 1, true),
-        Mon::new(70, 100, 70, 45, 45, 55, (0, 13), 127, 20, 192, 39, 0, 1, true),
-        Mon::new(100, 125, 100, 35, 55, 85, (0, 13), 127, 20, 192, 39, 0, 1, true),
+        Mon::new(70, 100, 70, 45, 45, 55, (0, 269), 127, 20, 192, 39, 0, 1, true),
+        Mon::new(100, 125, 100, 35, 55, 85, (0, 269), 127, 20, 192, 39, 0, 1, true),
         Mon::new(38, 40, 52, 27, 40, 72, (0, 243), 127, 199, 199, 11, 0, 1, true),
         Mon::new(68, 70, 92, 42, 50, 132, (0, 243), 127, 199, 199, 11, 0, 1, true),
         Mon::new(40, 55, 35, 35, 50, 35, (0, 239), 127, 102, 102, 126, 0, 1, true),
@@ -773,10 +773,10 @@ lazy_static! {
         Mon::new(68, 64, 60, 117, 111, 60, (0, 228), 254, 212, 212, 12, 0, 1, true),
         Mon::new(70, 75, 50, 50, 45, 50, (0, 0), 127, 218, 103, 56, 0, 1, true),
         Mon::new(120, 125, 80, 60, 55, 60, (0, 0), 127, 218, 103, 127, 0, 1, true),
-        Mon::new(42, 30, 38, 32, 30, 38, (0, 116), 254, 102, 12, 175, 0, 1, true),
-        Mon::new(52, 40, 48, 62, 40, 48, (0, 116), 254, 102, 12, 175, 0, 1, true),
-        Mon::new(72, 120, 98, 72, 50, 98, (116, 0), 254, 102, 214, 175, 0, 1, true),
-        Mon::new(51, 52, 90, 100, 82, 110, (0, 115), 191, 166, 205, 30, 0, 1, true),
+        Mon::new(42, 30, 38, 32, 30, 38, (0, 884), 254, 102, 12, 175, 0, 1, true),
+        Mon::new(52, 40, 48, 62, 40, 48, (0, 884), 254, 102, 12, 175, 0, 1, true),
+        Mon::new(72, 120, 98, 72, 50, 98, (884, 0), 254, 102, 214, 175, 0, 1, true),
+        Mon::new(51, 52, 90, 100, 82, 110, (0, 883), 191, 166, 205, 30, 0, 1, true),
         Mon::new(90, 60, 80, 60, 90, 110, (0, 0), 127, 39, 140, 180, 0, 1, true),
         Mon::new(100, 120, 90, 80, 40, 60, (0, 0), 127, 222, 222, 128, 0, 1, true),
         Mon::new(25, 35, 40, 80, 20, 30, (0, 0), 127, 193, 193, 193, 0, 1, true),
@@ -789,14 +789,14 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(60, 78, 135, 36, 91, 85, (0, 249), 127, 75, 75, 75, 0, 1, true),
-        Mon::new(65, 98, 63, 96, 40, 73, (0, 113), 127, 160, 31, 5, 0, 1, true),
+        Mon::new(65, 98, 63, 96, 40, 73, (0, 881), 127, 160, 31, 5, 0, 1, true),
         Mon::new(55, 90, 80, 96, 50, 105, (0, 150), 127, 209, 209, 209, 1165, 2, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(78, 60, 85, 36, 135, 91, (0, 156), 127, 201, 157, 13, 0, 1, true),
         Mon::new(70, 131, 100, 40, 86, 90, (0, 0), 255, 200, 200, 200, 0, 1, true),
-        Mon::new(45, 55, 65, 45, 45, 45, (0, 70), 127, 171, 43, 142, 0, 1, true),
-        Mon::new(55, 75, 90, 65, 65, 70, (0, 70), 127, 171, 43, 142, 0, 1, true),
-        Mon::new(75, 110, 125, 85, 100, 105, (70, 0), 127, 171, 43, 142, 0, 1, true),
+        Mon::new(45, 55, 65, 45, 45, 45, (0, 326), 127, 171, 43, 142, 0, 1, true),
+        Mon::new(55, 75, 90, 65, 65, 70, (0, 326), 127, 171, 43, 142, 0, 1, true),
+        Mon::new(75, 110, 125, 85, 100, 105, (326, 0), 127, 171, 43, 142, 0, 1, true),
         Mon::new(70, 115, 85, 130, 95, 75, (0, 0), 255, 226, 226, 140, 0, 1, true),
         Mon::new(70, 85, 75, 95, 130, 115, (0, 0), 255, 227, 227, 140, 0, 1, true),
         Mon::new(70, 130, 115, 75, 85, 95, (0, 0), 255, 229, 229, 140, 0, 1, true),
@@ -837,8 +837,8 @@ lazy_static! {
         Mon::new(68, 67, 55, 77, 43, 55, (0, 0), 127, 51, 127, 145, 0, 1, true),
         Mon::new(98, 87, 105, 67, 53, 85, (0, 0), 127, 46, 127, 240, 0, 1, true),
         Mon::new(25, 20, 20, 45, 25, 45, (0, 0), 127, 68, 14, 140, 0, 1, true),
-        Mon::new(50, 35, 80, 30, 50, 90, (0, 114), 127, 68, 14, 140, 0, 1, true),
-        Mon::new(60, 45, 110, 90, 80, 120, (0, 114), 127, 68, 119, 140, 0, 1, true),
+        Mon::new(50, 35, 80, 30, 50, 90, (0, 882), 127, 68, 14, 140, 0, 1, true),
+        Mon::new(60, 45, 110, 90, 80, 120, (0, 882), 127, 68, 119, 140, 0, 1, true),
         Mon::new(40, 28, 28, 50, 47, 52, (0, 0), 127, 50, 84, 198, 0, 1, true),
         Mon::new(70, 58, 58, 90, 87, 92, (0, 0), 127, 50, 84, 198, 0, 1, true),
         Mon::new(40, 40, 60, 10, 40, 60, (0, 0), 127, 238, 144, 27, 0, 1, true),
@@ -877,21 +877,21 @@ lazy_static! {
         Mon::new(93, 90, 101, 95, 60, 81, (0, 0), 127, 120, 62, 128, 0, 1, true),
         Mon::new(70, 110, 100, 50, 50, 60, (0, 0), 127, 4, 181, 252, 0, 1, true),
         Mon::new(60, 95, 50, 30, 145, 130, (0, 0), 191, 133, 133, 253, 0, 1, true),
-        Mon::new(62, 135, 95, 65, 68, 82, (0, 3), 127, 80, 80, 113, 0, 1, true),
+        Mon::new(62, 135, 95, 65, 68, 82, (0, 259), 127, 80, 80, 113, 0, 1, true),
         Mon::new(80, 85, 75, 70, 110, 100, (0, 0), 127, 77, 251, 115, 0, 1, true),
         Mon::new(58, 95, 145, 30, 50, 105, (0, 0), 127, 254, 254, 254, 0, 1, true),
         Mon::new(45, 40, 40, 34, 50, 61, (0, 0), 254, 175, 175, 165, 0, 1, true),
         Mon::new(65, 60, 75, 64, 110, 121, (0, 0), 254, 175, 175, 165, 1174, 9, true),
         Mon::new(65, 100, 100, 75, 70, 60, (0, 0), 255, 4, 4, 128, 0, 1, true),
         Mon::new(48, 101, 95, 15, 91, 85, (0, 0), 127, 31, 31, 226, 0, 1, true),
-        Mon::new(30, 25, 35, 20, 45, 30, (0, 137), 127, 19, 19, 246, 0, 1, true),
+        Mon::new(30, 25, 35, 20, 45, 30, (0, 649), 127, 19, 19, 246, 0, 1, true),
         Mon::new(70, 65, 60, 65, 125, 90, (0, 0), 127, 19, 19, 246, 0, 1, true),
         Mon::new(100, 125, 135, 70, 20, 20, (0, 0), 127, 249, 249, 249, 0, 1, true),
         Mon::new(75, 80, 110, 50, 65, 90, (0, 0), 127, 248, 248, 248, 1182, 2, true),
         Mon::new(60, 65, 55, 95, 105, 95, (0, 0), 0, 39, 28, 227, 1183, 2, true),
         Mon::new(58, 95, 58, 97, 70, 58, (0, 0), 127, 258, 258, 258, 1184, 2, true),
-        Mon::new(72, 80, 49, 40, 40, 49, (0, 23), 127, 125, 125, 134, 0, 1, true),
-        Mon::new(122, 130, 69, 30, 80, 69, (0, 23), 127, 125, 125, 134, 0, 1, true),
+        Mon::new(72, 80, 49, 40, 40, 49, (0, 279), 127, 125, 125, 134, 0, 1, true),
+        Mon::new(122, 130, 69, 30, 80, 69, (0, 279), 127, 125, 125, 134, 0, 1, true),
         Mon::new(90, 100, 90, 75, 80, 70, (0, 0), 255, 10, 55, 146, 0, 1, true),
         Mon::new(90, 100, 90, 55, 90, 80, (0, 0), 255, 10, 9, 202, 0, 1, true),
         Mon::new(90, 90, 100, 75, 70, 80, (0, 0), 255, 11, 173, 146, 0, 1, true),
@@ -923,10 +923,10 @@ lazy_static! {
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(35, 55, 40, 90, 50, 50, (0, 236), 0, 9, 9, 31, 901, 10, true),
         Mon::new(60, 85, 50, 110, 95, 85, (0, 0), 127, 207, 207, 207, 910, 2, true),
-        Mon::new(50, 75, 90, 40, 10, 35, (0, 30), 127, 81, 81, 202, 911, 2, true),
-        Mon::new(75, 100, 120, 65, 25, 65, (0, 30), 127, 81, 81, 202, 912, 2, true),
-        Mon::new(38, 41, 40, 65, 50, 65, (0, 137), 191, 81, 81, 117, 913, 2, true),
-        Mon::new(73, 67, 75, 109, 81, 100, (0, 137), 191, 81, 81, 117, 914, 2, true),
+        Mon::new(50, 75, 90, 40, 10, 35, (0, 286), 127, 81, 81, 202, 911, 2, true),
+        Mon::new(75, 100, 120, 65, 25, 65, (0, 286), 127, 81, 81, 202, 912, 2, true),
+        Mon::new(38, 41, 40, 65, 50, 65, (0, 649), 191, 81, 81, 117, 913, 2, true),
+        Mon::new(73, 67, 75, 109, 81, 100, (0, 649), 191, 81, 81, 117, 914, 2, true),
         Mon::new(10, 55, 30, 90, 35, 45, (0, 237), 127, 8, 221, 159, 915, 2, true),
         Mon::new(35, 100, 60, 110, 50, 70, (0, 237), 127, 8, 221, 159, 916, 2, true),
         Mon::new(40, 35, 35, 90, 50, 40, (0, 217), 127, 53, 101, 155, 917, 3, true),
@@ -940,12 +940,12 @@ lazy_static! {
         Mon::new(90, 65, 65, 15, 40, 40, (0, 0), 127, 82, 20, 144, 925, 2, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(95, 100, 95, 30, 100, 70, (0, 0), 127, 259, 20, 144, 926, 3, true),
-        Mon::new(52, 95, 55, 55, 58, 62, (3, 0), 127, 80, 80, 113, 928, 2, true),
+        Mon::new(52, 95, 55, 55, 58, 62, (259, 0), 127, 80, 80, 113, 928, 2, true),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(0, 0, 0, 0, 0, 0, (0, 0), 255, 0, 0, 0, 0, 0, false),
         Mon::new(95, 105, 85, 45, 125, 75, (0, 0), 127, 119, 119, 139, 931, 2, true),
-        Mon::new(60, 80, 110, 45, 50, 80, (0, 2), 127, 130, 31, 69, 932, 2, true),
-        Mon::new(65, 90, 120, 60, 85, 70, (0, 115), 127, 26, 256, 228, 933, 2, true),
+        Mon::new(60, 80, 110, 45, 50, 80, (0, 258), 127, 130, 31, 69, 932, 2, true),
+        Mon::new(65, 90, 120, 60, 85, 70, (0, 883), 127, 26, 256, 228, 933, 2, true),
         Mon::new(50, 65, 65, 100, 90, 90, (0, 0), 127, 72, 251, 115, 934, 2, true),
         Mon::new(90, 85, 85, 95, 125, 100, (0, 0), 255, 172, 172, 172, 935, 2, true),
         Mon::new(90, 125, 90, 100, 85, 90, (0, 0), 255, 128, 128, 128, 936, 2, true),
@@ -74,6 +74,10 @@ export function createRaid(encounter: DenEncounter): Raid {
         encounter.isGmax,
         encounter.abilityPool,
         encounter.genderPool,
+        0, // Den raids are randomly shiny.
+        // Use the highest star rating the encounter appears at, and never 0 for an encounter
+        // that has no star flags set.
+        Math.max(encounter.stars.lastIndexOf(true) + 1, 1),
     )
 }
 